@@ -57,23 +57,28 @@ fn setup(
         VirtualCamera {
             director: director_entity,
             priority: 1,
+            enabled: true,
             blend_in: CameraBlendDefinition {
                 function: EaseFunction::CubicInOut,
                 duration: std::time::Duration::from_secs(1),
+                live: true,
+                ..default()
             },
         },
         Transform::from_translation(Vec3::ZERO),
         Projection::Perspective(PerspectiveProjection::default()),
-        FollowTarget { target: blue, offset: Vec3::Z, damping: 0. }
+        FollowTarget { target: blue, offset: Vec3::Z, damping: Damping::new(0.), look_ahead: None }
     )).id();
 
     let cam2 = commands.spawn((
         VirtualCamera {
             director: director_entity,
             priority: 0,
+            enabled: true,
             blend_in: CameraBlendDefinition {
                 function: EaseFunction::Elastic(20.),
                 duration: std::time::Duration::from_secs(1),
+                ..default()
             },
         },
         Transform::from_translation(Vec3::new(0., 5., 15.,)),
@@ -82,10 +87,33 @@ fn setup(
             target: red,
             offset: Vec3::ZERO,
             dead_zone: DeadZone { xmin: -0.5, ymin: -0.5, xmax: 0.5, ymax: 0.5 },
-            damping: 1.
+            damping: Damping::new(1.),
+            ..default()
         } 
     )).id();
 
+    // Cut straight into camera 2 and ease back out of it.  The matrix wins over
+    // blend_in, so camera 2's elastic blend_in never plays.
+    commands.entity(director_entity).insert(
+        Director::new(camera_entity).with_blend_matrix(
+            CameraBlendMatrix::new()
+                .with(
+                    BlendCamera::Any,
+                    BlendCamera::Camera(cam2),
+                    CameraBlendDefinition::cut(),
+                )
+                .with(
+                    BlendCamera::Camera(cam2),
+                    BlendCamera::Any,
+                    CameraBlendDefinition {
+                        function: EaseFunction::QuadraticOut,
+                        duration: std::time::Duration::from_secs(2),
+                        ..default()
+                    },
+                ),
+        ),
+    );
+
     commands.insert_resource(Cameras {cam2});
     commands.spawn(
         Text("Press Space Bar".to_string())
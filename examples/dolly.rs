@@ -0,0 +1,89 @@
+mod shared;
+use bevy::prelude::*;
+use bevy_virtual_cameras::prelude::*;
+
+fn main() {
+    let mut app = shared::get_app();
+    app.add_systems(Startup, setup)
+        .add_systems(Update, switch_mode)
+        .run();
+}
+
+// 1 rides at constant speed, 2 tracks the red target, 3 parks halfway
+fn switch_mode(
+    mut carts: Query<&mut DollyCart>,
+    targets: Query<Entity, With<shared::Target1>>,
+    keys: Res<ButtonInput<KeyCode>>,
+) {
+    let (Ok(mut cart), Ok(red)) = (carts.single_mut(), targets.single()) else {
+        return;
+    };
+
+    if keys.just_pressed(KeyCode::Digit1) {
+        cart.mode = DollyCartMode::ConstantSpeed(3.);
+    }
+    if keys.just_pressed(KeyCode::Digit2) {
+        cart.mode = DollyCartMode::TrackTarget(red);
+    }
+    if keys.just_pressed(KeyCode::Digit3) {
+        cart.mode = DollyCartMode::Position(0.5);
+    }
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let (red, _blue) = shared::setup(&mut commands, &mut *meshes, &mut *materials);
+
+    // 1️⃣ Spawn a camera entity
+    let camera_entity = commands
+        .spawn((
+            Camera3d::default(),
+            Transform::from_translation(Vec3::new(0.0, 5.0, 10.0)).looking_at(Vec3::ZERO, Vec3::Y),
+        ))
+        .id();
+
+    // 2️⃣ Spawn a director entity
+    let director_entity = commands.spawn(Director::new(camera_entity)).id();
+
+    // 3️⃣ Spawn a looped track winding around the scene
+    let points = (0..8).map(|i| {
+        let angle = i as f32 / 8. * std::f32::consts::TAU;
+        let radius = if i % 2 == 0 { 12. } else { 9. };
+        Vec3::new(
+            angle.cos() * radius,
+            3. + (angle * 2.).sin() * 1.5,
+            angle.sin() * radius,
+        )
+    });
+    let track = commands
+        .spawn((
+            DollyTrack::catmull_rom_looped(points).expect("track has enough points"),
+            Transform::IDENTITY,
+            TrackGizmo,
+        ))
+        .id();
+
+    // 4️⃣ Spawn a virtual camera riding the track while it looks at the red target
+    commands.spawn((
+        VirtualCamera {
+            director: director_entity,
+            priority: 1,
+            ..default()
+        },
+        Transform::IDENTITY,
+        Projection::Perspective(PerspectiveProjection::default()),
+        DollyCart {
+            track,
+            mode: DollyCartMode::TrackTarget(red),
+            ..default()
+        },
+        LookAtTarget {
+            target: red,
+            damping: Damping::new(0.3),
+            ..default()
+        },
+    ));
+}
@@ -35,14 +35,15 @@ fn setup(
         VirtualCamera {
             director: director_entity,
             priority: 1,
-            blend_in: CameraBlendDefinition::default(),
+            ..default()
         },
         Transform::IDENTITY,
         Projection::Perspective(PerspectiveProjection::default()),
         FollowTarget {
             target: blue,
             offset: Vec3::Z * 2.,
-            damping: 0.0,
+            damping: Damping::new(0.0),
+            look_ahead: Some(LookAhead::new(0.3, 0.2).ignore_vertical()),
         },
     ));
 
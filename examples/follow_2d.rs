@@ -0,0 +1,75 @@
+use bevy::prelude::*;
+use bevy_virtual_cameras::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(VirtualCameraPlugin::default())
+        .add_systems(Startup, setup)
+        .add_systems(Update, move_targets)
+        .run();
+}
+
+#[derive(Component)]
+struct Mover(f32);
+
+fn move_targets(time: Res<Time>, mut query: Query<(&mut Transform, &Mover)>) {
+    for (mut t, mover) in &mut query {
+        t.translation.x = (time.elapsed_secs() * mover.0).sin() * 300.0;
+        t.translation.y = (time.elapsed_secs() * mover.0 * 1.3).cos() * 150.0;
+    }
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    // 1️⃣ Spawn a couple of moving targets
+    let square = meshes.add(Rectangle::new(40., 40.));
+    let red = commands
+        .spawn((
+            Mesh2d(square.clone()),
+            MeshMaterial2d(materials.add(Color::LinearRgba(LinearRgba::RED))),
+            Transform::IDENTITY,
+            Mover(1.0),
+        ))
+        .id();
+    let blue = commands
+        .spawn((
+            Mesh2d(square),
+            MeshMaterial2d(materials.add(Color::LinearRgba(LinearRgba::BLUE))),
+            Transform::IDENTITY,
+            Mover(0.6),
+        ))
+        .id();
+
+    // 2️⃣ Spawn a pixel snapped 2D camera and its director
+    let camera_entity = commands.spawn((Camera2d, PixelSnap)).id();
+    let director_entity = commands.spawn(Director::new(camera_entity)).id();
+    let group = commands.spawn(TargetGroup::from_iter([red, blue])).id();
+
+    // 3️⃣ Spawn a virtual camera that follows red and zooms out to keep blue in view
+    commands.spawn((
+        VirtualCamera {
+            director: director_entity,
+            priority: 1,
+            ..default()
+        },
+        Transform::IDENTITY,
+        Projection::Orthographic(OrthographicProjection::default_2d()),
+        Follow2d {
+            target: red,
+            dead_zone: Rect::new(-100., -60., 100., 60.),
+            damping: Damping::new(0.3),
+            ..default()
+        },
+        ZoomToFit2d {
+            group,
+            padding: 50.,
+            damping: Damping::new(0.5),
+            min_scale: 1.,
+            ..default()
+        },
+    ));
+}
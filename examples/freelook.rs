@@ -1,27 +1,14 @@
 mod shared;
-use bevy::{input::mouse::{AccumulatedMouseMotion}, prelude::*};
+use bevy::prelude::*;
 use bevy_virtual_cameras::prelude::*;
 
 fn main() {
     let mut app = shared::get_app();
     app
         .add_systems(Startup, setup)
-        .add_systems(Update, input)
         .run();
 }
 
-fn input (
-    mut cam: Query<&mut FreeLook>,
-    input: Res<AccumulatedMouseMotion>,
-    time: Res<Time>,
-) {
-    let Ok(mut cam) = cam.single_mut() else { return };
-    let delta = input.delta;
-    const LOOK_SPEED: f32 = 0.1;
-    cam.pitch += delta.y * time.delta_secs() * LOOK_SPEED;
-    cam.yaw += delta.x * time.delta_secs() * LOOK_SPEED;
-}
-
 fn setup(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
@@ -42,12 +29,12 @@ fn setup(
         .spawn(Director::new(camera_entity))
         .id();
 
-    // 3️⃣ Spawn a virtual camera for freelook
+    // 3️⃣ Spawn a virtual camera for freelook, driven by the mouse or right stick
     commands.spawn((
         VirtualCamera {
             director: director_entity,
             priority: 1,
-            blend_in: CameraBlendDefinition::default(),
+            ..default()
         },
         Transform::IDENTITY,
         Projection::Perspective(PerspectiveProjection::default()),
@@ -55,6 +42,7 @@ fn setup(
             pitch_limit: 1.5, // Little bit less than PI / 2 up and down
             ..default()
         },
+        CameraInput::default(),
     ));
 
 }
\ No newline at end of file
@@ -30,7 +30,7 @@ fn setup(
         VirtualCamera {
             director: director_entity,
             priority: 1,
-            blend_in: CameraBlendDefinition::default(),
+            ..default()
         },
         Transform::from_translation(Vec3::new(0.0, 5.0, 10.0)).looking_at(Vec3::ZERO, Vec3::Y),
         Projection::Perspective(PerspectiveProjection::default()),
@@ -43,7 +43,16 @@ fn setup(
                 ymin: -0.5,
                 ymax: 0.5,
             },
-            damping: 0.5,
+            soft_zone: DeadZone {
+                xmin: -0.8,
+                xmax: 0.8,
+                ymin: -0.8,
+                ymax: 0.8,
+            },
+            // Settle the target on the right third of the screen
+            aim: Vec2::new(1. / 3., 0.),
+            damping: Damping::new(0.5),
+            ..default()
         },
     ));
 }
@@ -5,38 +5,17 @@ use bevy_virtual_cameras::prelude::*;
 fn main() {
     let mut app = shared::get_app();
     app
+        .add_plugins(DeocclusionPlugin::<MeshAabbBackend>::default())
         .add_systems(Startup, setup)
-        .add_systems(Update, input)
         .run();
 }
 
-fn input(
-    input: Res<ButtonInput<KeyCode>>,
-    mut orbit: Query<&mut OrbitArm>,
-    time: Res<Time>,
-) {
-    const ORBIT_SPEED: f32 = 2.0;
-    let Ok(mut orbit) = orbit.single_mut() else { return };
-    if input.pressed(KeyCode::KeyD) {
-        orbit.yaw += time.delta_secs() * ORBIT_SPEED;
-    }
-    if input.pressed(KeyCode::KeyA) {
-        orbit.yaw -= time.delta_secs() * ORBIT_SPEED;
-    }
-    if input.pressed(KeyCode::KeyW) {
-        orbit.pitch += time.delta_secs() * ORBIT_SPEED;
-    }
-    if input.pressed(KeyCode::KeyS) {
-        orbit.pitch -= time.delta_secs() * ORBIT_SPEED;
-    }
-}
-
 fn setup(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
 ) {
-    let (_red, blue) = shared::setup(&mut commands, &mut *meshes, &mut *materials);
+    let (red, blue) = shared::setup(&mut commands, &mut *meshes, &mut *materials);
 
     // 1️⃣ Spawn a camera entity
     let camera_entity = commands
@@ -51,12 +30,12 @@ fn setup(
         .spawn(Director::new(camera_entity))
         .id();
 
-    // 3️⃣ Spawn a virtual camera that orbits the blue target
+    // 3️⃣ Spawn a virtual camera that orbits the blue target, staying in front of walls
     commands.spawn((
         VirtualCamera {
             director: director_entity,
             priority: 1,
-            blend_in: CameraBlendDefinition::default(),
+            ..default()
         },
         Transform::IDENTITY,
         Projection::Perspective(PerspectiveProjection::default()),
@@ -65,7 +44,16 @@ fn setup(
             radius: 5.,
             offset: Vec3::X,
             ..default()
-        }
+        },
+        // Drag with the left mouse button or use the right stick, scroll to zoom
+        CameraInput {
+            mouse_button: Some(MouseButton::Left),
+            ..default()
+        },
+        Deoccluder {
+            ignore: vec![red],
+            ..default()
+        },
     ));
 
 }
\ No newline at end of file
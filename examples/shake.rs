@@ -13,12 +13,25 @@ fn main() {
 fn input(
     input: Res<ButtonInput<KeyCode>>,
     mut cam: Query<Entity, With<VirtualCamera>>,
+    real_cam: Query<Entity, With<Camera3d>>,
+    sources: Query<Entity, With<ImpulseSource>>,
     mut writer: MessageWriter<AddCameraShake>,
+    mut impulses: MessageWriter<GenerateImpulse>,
+    asset_server: Res<AssetServer>,
 ) {
     let Ok(cam) = cam.single_mut() else { return };
-    if input.just_pressed(KeyCode::Space) {
-        writer.write(AddCameraShake {
-            vcam_entity: cam,
+
+    // Space for a one off shake, R to shake the real camera instead
+    let target = if input.just_pressed(KeyCode::KeyR) {
+        real_cam.single().ok()
+    } else if input.just_pressed(KeyCode::Space) {
+        Some(cam)
+    } else {
+        None
+    };
+    if let Some(target) = target {
+        writer.write(AddCameraShake::Shake {
+            camera: target,
             camera_shake: Shake {
                 timer: Timer::from_seconds(1.5, TimerMode::Once),
                 translation_intensity: Vec3::new(0.1, 0.2, 0.3),
@@ -30,6 +43,33 @@ fn input(
             }
         });
     }
+
+    // Enter for an explosion, press it repeatedly to stack trauma
+    if input.just_pressed(KeyCode::Enter) {
+        writer.write(AddCameraShake::Trauma {
+            camera: cam,
+            amount: 0.4,
+        });
+    }
+
+    // P for the explosion preset in assets/shakes, edit it while running with the file_watcher feature
+    if input.just_pressed(KeyCode::KeyP) {
+        writer.write(AddCameraShake::Profile {
+            camera: cam,
+            profile: asset_server.load("shakes/explosion.shake.ron"),
+            scale: 1.,
+        });
+    }
+
+    // I for a stomp from blue, felt less the further away it is
+    if input.just_pressed(KeyCode::KeyI) {
+        for source in sources.iter() {
+            impulses.write(GenerateImpulse {
+                source,
+                strength: 1.,
+            });
+        }
+    }
 }
 
 fn setup(
@@ -37,7 +77,19 @@ fn setup(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
 ) {
-    let (_red, _blue) = shared::setup(&mut commands, &mut *meshes, &mut *materials);
+    let (red, blue) = shared::setup(&mut commands, &mut *meshes, &mut *materials);
+    commands.entity(blue).insert(ImpulseSource {
+        shake: Shake {
+            timer: Timer::from_seconds(0.6, TimerMode::Once),
+            translation_intensity: Vec3::new(0., 0.3, 0.),
+            translation_frequency: Vec3::splat(12.),
+            waveform: ShakeWaveform::NOISE,
+            damping: 1.0,
+            ..default()
+        },
+        radius: 10.,
+        propagation_speed: 20.,
+    });
 
     // 1️⃣ Spawn a camera entity
     let camera_entity = commands
@@ -52,15 +104,29 @@ fn setup(
         .spawn(Director::new(camera_entity))
         .id();
 
-    // 3️⃣ Spawn a virtual camera to shake
+    // 3️⃣ Spawn a virtual camera to shake, it keeps tracking red while shaking
     commands.spawn((
         VirtualCamera {
             director: director_entity,
             priority: 1,
-            blend_in: CameraBlendDefinition::default(),
+            ..default()
         },
         Transform::from_translation(Vec3::Z * 2.),
         Projection::Perspective(PerspectiveProjection::default()),
+        LookAtTarget {
+            target: red,
+            damping: Damping::new(0.2),
+            ..default()
+        },
+        ImpulseListener::default(),
+        Trauma::new(Shake {
+            translation_intensity: Vec3::new(0.3, 0.3, 0.1),
+            rotation_intensity: Vec3::new(0.05, 0.05, 0.1),
+            translation_frequency: Vec3::splat(4.),
+            rotation_frequency: Vec3::splat(4.),
+            waveform: ShakeWaveform::NOISE,
+            ..default()
+        }),
     ));
 
 }
\ No newline at end of file
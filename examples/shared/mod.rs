@@ -6,7 +6,7 @@ use bevy_virtual_cameras::prelude::*;
 pub fn get_app() -> App {
     let mut app = App::new();
     app.add_plugins(DefaultPlugins)
-        .add_plugins(VirtualCameraPlugin)
+        .add_plugins(VirtualCameraPlugin::default())
         .add_systems(Update, move_target);
     app
 }
@@ -0,0 +1,90 @@
+mod shared;
+use bevy::{prelude::*, window::PrimaryWindow};
+use bevy_virtual_cameras::prelude::*;
+
+fn main() {
+    let mut app = shared::get_app();
+    app
+        .add_systems(Startup, setup)
+        .add_systems(Update, input)
+        .run();
+}
+
+#[derive(Resource)]
+struct Players {
+    cameras: Vec<Entity>,
+}
+
+fn input(
+    input: Res<ButtonInput<KeyCode>>,
+    players: Res<Players>,
+    mut layout: Single<&mut SplitScreen>,
+    mut cameras: Query<&mut Camera>,
+) {
+    // Add or remove the third player
+    if input.just_pressed(KeyCode::Space) {
+        let third = players.cameras[2];
+        let joined = !layout.cameras.contains(&third);
+        if joined {
+            layout.cameras.push(third);
+        } else {
+            layout.cameras.retain(|e| *e != third);
+        }
+        if let Ok(mut camera) = cameras.get_mut(third) {
+            camera.is_active = joined;
+        }
+    }
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    window: Single<Entity, With<PrimaryWindow>>,
+) {
+    let (red, blue) = shared::setup(&mut commands, &mut *meshes, &mut *materials);
+
+    // 1️⃣ Spawn one camera and director per player, each with its own virtual camera
+    let mut cameras = vec![];
+    for (i, target) in [red, blue, red].into_iter().enumerate() {
+        let camera_entity = commands
+            .spawn((
+                Camera3d::default(),
+                Camera {
+                    order: i as isize,
+                    is_active: i < 2,
+                    ..default()
+                },
+                Transform::IDENTITY,
+            ))
+            .id();
+
+        let director_entity = commands
+            .spawn(Director::new(camera_entity))
+            .id();
+
+        commands.spawn((
+            VirtualCamera {
+                director: director_entity,
+                priority: 1,
+                ..default()
+            },
+            Transform::from_translation(Vec3::new(0., 3., 10.)),
+            Projection::Perspective(PerspectiveProjection::default()),
+            OrbitArm {
+                target,
+                radius: 5. + i as f32 * 3.,
+                yaw: i as f32,
+                ..default()
+            },
+        ));
+        cameras.push(camera_entity);
+    }
+
+    // 2️⃣ Lay out the first two players, the third joins with Space
+    commands.spawn(SplitScreen::new(*window, cameras[..2].to_vec()));
+    commands.insert_resource(Players { cameras });
+    commands.spawn(
+        Text("Press Space Bar".to_string())
+    );
+}
@@ -0,0 +1,70 @@
+mod shared;
+use bevy::prelude::*;
+use bevy_virtual_cameras::prelude::*;
+
+fn main() {
+    let mut app = shared::get_app();
+    app
+        .add_systems(Startup, setup)
+        .add_systems(Update, (input, draw_aim))
+        .run();
+}
+
+fn input(
+    mut rig: Query<&mut ThirdPersonFollow>,
+    buttons: Res<ButtonInput<MouseButton>>,
+    keys: Res<ButtonInput<KeyCode>>,
+) {
+    let Ok(mut rig) = rig.single_mut() else { return };
+
+    // Right mouse to aim, Q to swap shoulders
+    rig.aiming = buttons.pressed(MouseButton::Right);
+    if keys.just_pressed(KeyCode::KeyQ) {
+        rig.swap_side();
+    }
+}
+
+// The screen center ray is the aim ray
+fn draw_aim(rig: Query<(&ThirdPersonFollow, &Transform)>, mut gizmos: Gizmos) {
+    let Ok((rig, transform)) = rig.single() else { return };
+    let ray = rig.aim_ray(transform);
+    gizmos.sphere(ray.get_point(10.), 0.1, Color::WHITE);
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let (_red, blue) = shared::setup(&mut commands, &mut *meshes, &mut *materials);
+
+    // 1️⃣ Spawn a camera entity
+    let camera_entity = commands
+        .spawn((
+            Camera3d::default(),
+            Transform::IDENTITY,
+        ))
+        .id();
+
+    // 2️⃣ Spawn a director entity
+    let director_entity = commands
+        .spawn(Director::new(camera_entity))
+        .id();
+
+    // 3️⃣ Spawn an over the shoulder camera behind the blue target
+    commands.spawn((
+        VirtualCamera {
+            director: director_entity,
+            priority: 1,
+            ..default()
+        },
+        Transform::IDENTITY,
+        Projection::Perspective(PerspectiveProjection::default()),
+        ThirdPersonFollow {
+            target: blue,
+            shoulder: Vec3::new(0.4, 0.5, 0.),
+            ..default()
+        },
+        CameraInput::default(),
+    ));
+}
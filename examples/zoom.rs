@@ -25,23 +25,28 @@ fn setup(
     // 2️⃣ Spawn a director entity
     let director_entity = commands.spawn(Director::new(camera_entity)).id();
 
-    // 3️⃣ Spawn a virtual camera that zooms to keep both objects in frame
+    // 3️⃣ Group both objects, red counts twice as much
+    let group = commands
+        .spawn(TargetGroup::new().with(red, 2., 0.5).with(blue, 1., 0.5))
+        .id();
+
+    // 4️⃣ Spawn a virtual camera that zooms to keep both objects in frame
     commands.spawn((
         VirtualCamera {
             director: director_entity,
             priority: 1,
-            blend_in: CameraBlendDefinition::default(),
+            ..default()
         },
         Transform::from_translation(Vec3::Z * 5.),
         Projection::Perspective(PerspectiveProjection::default()),
         GroupZoom {
-            targets: vec![red, blue],
-            damping: 2.0,
-            dead_zone: DeadZone {
-                xmin: -0.5,
-                xmax: 0.5,
-                ymin: -0.5,
-                ymax: 0.5,
+            group,
+            damping: Damping::new(2.0).critically_damped(),
+            screen_rect: DeadZone {
+                xmin: -0.6,
+                xmax: 0.6,
+                ymin: -0.6,
+                ymax: 0.6,
             },
             min_scale: 2.,
             ..default()
@@ -2,20 +2,196 @@ use std::time::Duration;
 
 use bevy::prelude::*;
 
-use crate::{camera_state::CameraState, prelude::*};
+use crate::{
+    camera_state::CameraState,
+    camera_time::{CameraTime, CameraTimeSource},
+    prelude::*,
+};
+
+/// A virtual camera's state as read by a blend, along with the point it is
+/// framing (if it has one).
+pub(crate) struct VcamSample {
+    pub state: CameraState,
+    pub pivot: Option<Vec3>,
+}
 
 #[derive(Clone, Debug)]
 pub struct CameraBlendState {
-    pub from: CameraState,
+    pub from: BlendSource,
     pub to: Entity,
     pub t: f32,
     pub(crate) definition: CameraBlendDefinition,
+    pub(crate) state: Option<CameraState>, // last evaluated state
+    pub(crate) origin: Option<Entity>,     // camera that was live when the blend started
+    pub(crate) paused: bool,
+    pub(crate) speed: f32,
+}
+
+/// Read only view of a director's blend in progress.
+#[derive(Clone, Copy, Debug)]
+pub struct CameraBlendProgress {
+    /// Camera that was live when the blend started
+    pub from: Option<Entity>,
+    pub to: Entity,
+    /// Elapsed fraction of the blend duration, in [0, 1]
+    pub progress: f32,
+    /// Blend weight of the incoming camera after easing
+    pub weight: f32,
+    /// Blend time left at the current speed
+    pub remaining: Duration,
+    pub paused: bool,
+    pub speed: f32,
+}
+
+/// How a director shrinks its blend stack once it exceeds the max depth.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BlendStackCollapse {
+    /// Freeze the oldest blend at its last evaluated state.
+    #[default]
+    Freeze,
+    /// Finish the oldest blend immediately, leaving only its target camera.
+    Finish,
+}
+
+/// Where the outgoing side of a blend reads its camera state from.
+#[derive(Clone, Debug)]
+pub enum BlendSource {
+    /// Camera state frozen when the blend started.
+    Snapshot(CameraState),
+    /// A virtual camera re-read every frame.  `last` is kept in case it despawns.
+    Live { vcam: Entity, last: CameraState },
+    /// A blend that was interrupted and keeps evaluating underneath.
+    Blend(Box<CameraBlendState>),
+}
+
+impl BlendSource {
+    /// The most recent state this source produced, without advancing it.
+    fn last_state(&self) -> CameraState {
+        match self {
+            BlendSource::Snapshot(state) => state.clone(),
+            BlendSource::Live { last, .. } => last.clone(),
+            BlendSource::Blend(blend) => blend
+                .state
+                .clone()
+                .unwrap_or_else(|| blend.from.last_state()),
+        }
+    }
+
+    fn evaluate(
+        &mut self,
+        delta: f32,
+        lookup: &impl Fn(Entity) -> Option<VcamSample>,
+    ) -> CameraState {
+        match self {
+            BlendSource::Snapshot(state) => state.clone(),
+            BlendSource::Live { vcam, last } => {
+                if let Some(sample) = lookup(*vcam) {
+                    *last = sample.state;
+                }
+                last.clone()
+            }
+            BlendSource::Blend(blend) => {
+                blend.t += delta;
+                let state = blend.evaluate(delta, lookup);
+
+                // A finished inner blend is just its target camera from now on
+                if blend.is_finished() {
+                    let vcam = blend.to;
+                    *self = BlendSource::Live {
+                        vcam,
+                        last: state.clone(),
+                    };
+                }
+                state
+            }
+        }
+    }
+}
+
+impl CameraBlendState {
+    pub(crate) fn is_finished(&self) -> bool {
+        self.t >= self.definition.duration.as_secs_f32()
+    }
+
+    fn progress(&self) -> f32 {
+        (self.t / self.definition.duration.as_secs_f32()).clamp(0.0, 1.0)
+    }
+
+    fn weight(&self) -> f32 {
+        self.definition.function.sample(self.progress()).unwrap()
+    }
+
+    pub(crate) fn view(&self) -> CameraBlendProgress {
+        let left = (self.definition.duration.as_secs_f32() - self.t).max(0.0);
+        let remaining = if left > 0.0 { left / self.speed } else { 0.0 };
+        CameraBlendProgress {
+            from: self.origin,
+            to: self.to,
+            progress: self.progress(),
+            weight: self.weight(),
+            // Never finishes at a speed of 0
+            remaining: Duration::try_from_secs_f32(remaining).unwrap_or(Duration::MAX),
+            paused: self.paused,
+            speed: self.speed,
+        }
+    }
+
+    /// Collapses nested blends so at most `max_depth` remain.
+    pub(crate) fn collapse(&mut self, max_depth: usize, policy: BlendStackCollapse) {
+        let BlendSource::Blend(inner) = &mut self.from else {
+            return;
+        };
+
+        if max_depth > 1 {
+            inner.collapse(max_depth - 1, policy);
+            return;
+        }
+
+        let replacement = match (policy, &inner.state) {
+            (BlendStackCollapse::Freeze, Some(state)) => BlendSource::Snapshot(state.clone()),
+            (_, state) => BlendSource::Live {
+                vcam: inner.to,
+                last: state.clone().unwrap_or_else(|| inner.from.last_state()),
+            },
+        };
+        self.from = replacement;
+    }
+
+    /// Evaluates the blend at its current time.  If the target camera can't
+    /// be read, holds the last evaluated state, like a despawned
+    /// [`BlendSource::Live`] camera.
+    pub(crate) fn evaluate(
+        &mut self,
+        delta: f32,
+        lookup: &impl Fn(Entity) -> Option<VcamSample>,
+    ) -> CameraState {
+        let from = self.from.evaluate(delta, lookup);
+        let Some(VcamSample { state: to, pivot }) = lookup(self.to) else {
+            return self.state.clone().unwrap_or(from);
+        };
+
+        let eased_t = self.weight();
+
+        let state = match (self.definition.mode, pivot) {
+            (BlendMode::Linear, _) | (_, None) => CameraState::interpolate(&from, &to, eased_t),
+            (mode, Some(pivot)) => {
+                CameraState::interpolate_around(&from, &to, pivot, eased_t, mode)
+            }
+        };
+        self.state = Some(state.clone());
+        state
+    }
 }
 
 #[derive(Clone, Debug)]
 pub struct CameraBlendDefinition {
     pub function: EaseFunction,
     pub duration: Duration,
+    /// Keep evaluating the outgoing camera during the blend instead of
+    /// freezing it where it was when the blend started.
+    pub live: bool,
+    /// How the camera position travels between the two cameras.
+    pub mode: BlendMode,
 }
 
 impl Default for CameraBlendDefinition {
@@ -23,60 +199,215 @@ impl Default for CameraBlendDefinition {
         Self {
             function: EaseFunction::Linear,
             duration: Duration::from_secs(1),
+            live: false,
+            mode: BlendMode::Linear,
         }
     }
 }
 
+/// Path the camera position takes during a blend.
+///
+/// The pivot for the arcing modes is the incoming camera's [`OrbitArm`] or
+/// [`LookAtTarget`] target.  Cameras without one blend linearly.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BlendMode {
+    /// Straight line between the two positions.
+    #[default]
+    Linear,
+    /// Arc around the pivot, interpolating direction and distance.
+    Spherical,
+    /// Arc around the pivot's vertical axis, keeping height linear.
+    Cylindrical,
+}
+
 impl CameraBlendDefinition {
-    pub(crate) fn create(&self, from: CameraState, to: Entity) -> CameraBlendState {
+    /// A blend that switches to the new camera immediately.
+    pub fn cut() -> Self {
+        Self {
+            duration: Duration::ZERO,
+            ..default()
+        }
+    }
+
+    /// Returns true if this definition switches cameras without blending.
+    pub fn is_cut(&self) -> bool {
+        self.duration.is_zero()
+    }
+
+    pub(crate) fn create(
+        &self,
+        from: BlendSource,
+        origin: Option<Entity>,
+        to: Entity,
+    ) -> CameraBlendState {
         CameraBlendState {
             from,
             to,
             t: 0.,
             definition: self.clone(),
+            state: None,
+            origin,
+            paused: false,
+            speed: 1.,
         }
     }
 }
 
+/// Matches the cameras on either side of a [`CameraBlendMatrix`] entry.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BlendCamera {
+    /// Matches any virtual camera.
+    #[default]
+    Any,
+    /// Matches a single virtual camera.
+    Camera(Entity),
+}
+
+impl BlendCamera {
+    fn matches(&self, entity: Option<Entity>) -> bool {
+        match self {
+            BlendCamera::Any => true,
+            BlendCamera::Camera(e) => entity == Some(*e),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CameraBlendMatrixEntry {
+    pub from: BlendCamera,
+    pub to: BlendCamera,
+    pub blend: CameraBlendDefinition,
+}
+
+/// Director-level table of blends between specific pairs of cameras.
+///
+/// Exact matches win over wildcard entries, and a wildcard on the source side
+/// wins over a wildcard on the target side.  When nothing matches, the
+/// target camera's [`VirtualCamera::blend_in`] is used.
+#[derive(Clone, Debug, Default)]
+pub struct CameraBlendMatrix {
+    pub entries: Vec<CameraBlendMatrixEntry>,
+}
+
+impl CameraBlendMatrix {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a blend for transitions from `from` to `to`.
+    pub fn with(
+        mut self,
+        from: BlendCamera,
+        to: BlendCamera,
+        blend: CameraBlendDefinition,
+    ) -> Self {
+        self.entries
+            .push(CameraBlendMatrixEntry { from, to, blend });
+        self
+    }
+
+    /// Finds the most specific blend for a transition, if any.
+    pub fn find(&self, from: Option<Entity>, to: Entity) -> Option<&CameraBlendDefinition> {
+        let specificity = |entry: &CameraBlendMatrixEntry| match (entry.from, entry.to) {
+            (BlendCamera::Camera(_), BlendCamera::Camera(_)) => 3,
+            (BlendCamera::Any, BlendCamera::Camera(_)) => 2,
+            (BlendCamera::Camera(_), BlendCamera::Any) => 1,
+            (BlendCamera::Any, BlendCamera::Any) => 0,
+        };
+
+        self.entries
+            .iter()
+            .filter(|entry| entry.from.matches(from) && entry.to.matches(Some(to)))
+            .fold(
+                None,
+                |best: Option<&CameraBlendMatrixEntry>, entry| match best {
+                    Some(best) if specificity(best) >= specificity(entry) => Some(best),
+                    _ => Some(entry),
+                },
+            )
+            .map(|entry| &entry.blend)
+    }
+}
+
 pub(crate) fn camera_blend_update_system(
     mut directors: Query<&mut Director>,
-    mut cameras: Query<(&mut Transform, &mut Projection), With<Camera3d>>,
-    vcams: Query<(&Transform, &Projection), (With<VirtualCamera>, Without<Camera3d>)>,
-    time: Res<Time<Real>>,
+    mut cameras: Query<(&mut Transform, &mut Projection, Has<Camera2d>), Without<VirtualCamera>>,
+    vcams: Query<
+        (
+            &Transform,
+            &Projection,
+            Option<&OrbitArm>,
+            Option<&LookAtTarget>,
+        ),
+        With<VirtualCamera>,
+    >,
+    targets: Query<&GlobalTransform>,
+    time_sources: Query<&CameraTimeSource>,
+    time: Res<CameraTime>,
     mut message_writer: MessageWriter<FinishedCameraBlend>,
 ) {
+    let lookup = |entity: Entity| {
+        let (transform, projection, orbit, look_at) = vcams.get(entity).ok()?;
+
+        // Arcing blends pivot around whatever the camera is framing
+        let pivot = if let Some(orbit) = orbit {
+            targets.get(orbit.target).ok().map(|t| t.translation())
+        } else if let Some(look_at) = look_at {
+            targets
+                .get(look_at.target)
+                .ok()
+                .map(|t| t.translation() + look_at.offset)
+        } else {
+            None
+        };
+
+        Some(VcamSample {
+            state: CameraState {
+                transform: *transform,
+                projection: projection.clone(),
+            },
+            pivot,
+        })
+    };
+
     for mut director in directors.iter_mut() {
         let camera_entity = director.camera_entity;
         if let Some(blend) = &mut director.blend {
-            // Advance blend
-            let duration = blend.definition.duration.as_secs_f32();
-            blend.t += time.delta_secs();
-            let progress = (blend.t / duration).clamp(0.0, 1.0);
-            let eased_t = blend.definition.function.sample(progress).unwrap();
-
-            // Get camera states
-            let Ok((to_transform, to_proj)) = vcams.get(blend.to) else {
+            // Wait for the target camera to exist
+            if !vcams.contains(blend.to) {
                 continue;
+            }
+
+            // Advance blend, the whole stack follows pause and speed
+            let delta = if blend.paused {
+                0.
+            } else {
+                time.delta_secs(time_sources.get(blend.to).ok()) * blend.speed
             };
+            blend.t += delta;
 
             // Interpolate state
-            let interpolated_state = CameraState::interpolate(
-                &blend.from,
-                &CameraState {
-                    transform: to_transform.clone(),
-                    projection: to_proj.clone(),
-                },
-                eased_t,
-            );
+            let interpolated_state = blend.evaluate(delta, &lookup);
 
             // Apply to real camera
-            if let Ok((mut camera, mut projection)) = cameras.get_mut(camera_entity) {
+            if let Ok((mut camera, mut projection, is_2d)) = cameras.get_mut(camera_entity) {
                 *camera = interpolated_state.transform;
                 *projection = interpolated_state.projection;
+
+                // 2D cameras only ever roll, never tip out of the XY plane
+                if is_2d {
+                    let q = camera.rotation;
+                    let flat = Quat::from_xyzw(0., 0., q.z, q.w);
+                    camera.rotation = if flat.length_squared() > f32::EPSILON {
+                        flat.normalize()
+                    } else {
+                        Quat::IDENTITY
+                    };
+                }
             }
 
             // Clean up finished blend
-            if blend.t >= duration {
+            if blend.is_finished() {
                 director.blend = None;
                 message_writer.write(FinishedCameraBlend {
                     to: director.active.unwrap(),
@@ -1,4 +1,9 @@
-use bevy::prelude::*;
+use bevy::{camera::ScalingMode, prelude::*};
+
+use crate::blend::BlendMode;
+
+/// Narrowest field of view used while dolly zooming towards an orthographic camera.
+const MIN_DOLLY_FOV: f32 = 0.01;
 
 #[derive(Clone, Debug)]
 pub struct CameraState {
@@ -9,6 +14,17 @@ pub struct CameraState {
 impl CameraState {
     /// Interpolates between two camera states.
     pub fn interpolate(from: &Self, to: &Self, t: f32) -> Self {
+        // Mixed projections dolly zoom through a nearly orthographic perspective
+        match (&from.projection, &to.projection) {
+            (Projection::Perspective(p), Projection::Orthographic(o)) => {
+                return Self::dolly_zoom(from, to, p, o, t);
+            }
+            (Projection::Orthographic(o), Projection::Perspective(p)) => {
+                return Self::dolly_zoom(to, from, p, o, 1.0 - t);
+            }
+            _ => {}
+        }
+
         // Interpolate transform
         let transform = Transform {
             translation: from.transform.translation.lerp(to.transform.translation, t),
@@ -50,4 +66,132 @@ impl CameraState {
             projection,
         }
     }
+
+    /// Interpolates between two camera states, moving the position along an
+    /// arc around `pivot` instead of a straight line.
+    pub fn interpolate_around(
+        from: &Self,
+        to: &Self,
+        pivot: Vec3,
+        t: f32,
+        mode: BlendMode,
+    ) -> Self {
+        let mut state = Self::interpolate(from, to, t);
+
+        // Dolly zooms already place the camera
+        if std::mem::discriminant(&from.projection) != std::mem::discriminant(&to.projection) {
+            return state;
+        }
+
+        let a = from.transform.translation - pivot;
+        let b = to.transform.translation - pivot;
+
+        let offset = match mode {
+            BlendMode::Linear => return state,
+            BlendMode::Spherical => {
+                let (Some(dir_a), Some(dir_b)) = (a.try_normalize(), b.try_normalize()) else {
+                    return state;
+                };
+                let arc = Quat::IDENTITY.slerp(Quat::from_rotation_arc(dir_a, dir_b), t);
+                arc * dir_a * (a.length() + (b.length() - a.length()) * t)
+            }
+            BlendMode::Cylindrical => {
+                let (radius_a, radius_b) = (a.xz().length(), b.xz().length());
+                let angle_a = a.z.atan2(a.x);
+                let angle_b = b.z.atan2(b.x);
+
+                // Take the short way around
+                let mut delta = angle_b - angle_a;
+                if delta > std::f32::consts::PI {
+                    delta -= std::f32::consts::TAU;
+                } else if delta < -std::f32::consts::PI {
+                    delta += std::f32::consts::TAU;
+                }
+
+                let angle = angle_a + delta * t;
+                let radius = radius_a + (radius_b - radius_a) * t;
+                Vec3::new(
+                    angle.cos() * radius,
+                    a.y + (b.y - a.y) * t,
+                    angle.sin() * radius,
+                )
+            }
+        };
+
+        state.transform.translation = pivot + offset;
+        state
+    }
+
+    /// Blends a perspective camera towards an orthographic one (`t` = 1) by
+    /// narrowing the field of view while pulling the camera back, so the frame
+    /// height at the focus distance matches the orthographic view throughout.
+    fn dolly_zoom(
+        persp: &Self,
+        ortho: &Self,
+        p: &PerspectiveProjection,
+        o: &OrthographicProjection,
+        t: f32,
+    ) -> Self {
+        if t >= 1.0 {
+            return ortho.clone();
+        }
+
+        // Distance at which the perspective frame is as tall as the orthographic one
+        let height = ortho_height(o, p.aspect_ratio);
+        let focus_distance = height / (2.0 * (p.fov * 0.5).tan());
+
+        let from_focus = persp.transform.translation + persp.transform.forward() * focus_distance;
+        let to_focus = ortho.transform.translation + ortho.transform.forward() * focus_distance;
+        let focus = from_focus.lerp(to_focus, t);
+        let rotation = persp.transform.rotation.slerp(ortho.transform.rotation, t);
+
+        // Narrow the FOV and back off to keep the focus plane framed
+        let fov = (p.fov + (MIN_DOLLY_FOV - p.fov) * t).max(MIN_DOLLY_FOV);
+        let distance = height / (2.0 * (fov * 0.5).tan());
+        let translation = focus - rotation * Vec3::NEG_Z * distance;
+
+        Self {
+            transform: Transform {
+                translation,
+                rotation,
+                scale: persp.transform.scale.lerp(ortho.transform.scale, t),
+            },
+            projection: Projection::Perspective(PerspectiveProjection {
+                fov,
+                far: p.far + (distance - focus_distance),
+                ..p.clone()
+            }),
+        }
+    }
+}
+
+/// World space height of an orthographic view.
+pub(crate) fn ortho_height(o: &OrthographicProjection, aspect_ratio: f32) -> f32 {
+    match o.scaling_mode {
+        ScalingMode::FixedVertical { viewport_height } => viewport_height * o.scale,
+        ScalingMode::FixedHorizontal { viewport_width } => viewport_width / aspect_ratio * o.scale,
+        ScalingMode::Fixed { height, .. } => height * o.scale,
+        ScalingMode::AutoMin {
+            min_width,
+            min_height,
+        } => min_height.max(min_width / aspect_ratio) * o.scale,
+        ScalingMode::AutoMax {
+            max_width,
+            max_height,
+        } => max_height.min(max_width / aspect_ratio) * o.scale,
+        ScalingMode::WindowSize => o.area.height(),
+    }
+}
+
+/// World space half extents of an orthographic view at a scale of 1, given the
+/// logical size of the viewport it renders to.
+pub(crate) fn ortho_half_size(o: &OrthographicProjection, viewport: Option<Vec2>) -> Vec2 {
+    match (&o.scaling_mode, viewport) {
+        (ScalingMode::WindowSize, Some(viewport)) => viewport * 0.5,
+        (_, viewport) => {
+            let aspect = viewport.map_or(1., |v| v.x / v.y);
+            let height = ortho_height(o, aspect) / o.scale;
+            Vec2::new(height * aspect, height) * 0.5
+        }
+    }
 }
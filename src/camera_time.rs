@@ -0,0 +1,86 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+/// Clock that drives damping, blends and shake.
+///
+/// Set for every camera through [`VirtualCameraPlugin`](crate::VirtualCameraPlugin),
+/// or insert on a virtual camera to override it for that camera.  Blends use
+/// the clock of the camera being blended to.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CameraTimeSource {
+    /// Wall clock time, unaffected by pausing or time scaling.
+    #[default]
+    Real,
+    /// Game time, follows `Time<Virtual>` pausing and relative speed.
+    Virtual,
+    /// Time consumed by the fixed timestep this frame.
+    Fixed,
+    /// `Time<CameraClock>`, advanced by the application.
+    Custom,
+}
+
+/// Custom clock for [`CameraTimeSource::Custom`].  Insert a `Time<CameraClock>`
+/// resource and advance it however you like.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CameraClock;
+
+/// Frame deltas for every camera clock.
+#[derive(Resource, Debug, Default)]
+pub(crate) struct CameraTime {
+    default_source: CameraTimeSource,
+    real: f32,
+    virtual_delta: f32,
+    fixed: f32,
+    custom: f32,
+    last_fixed: Duration,
+    last_custom: Duration,
+}
+
+impl CameraTime {
+    pub(crate) fn new(default_source: CameraTimeSource) -> Self {
+        Self {
+            default_source,
+            ..default()
+        }
+    }
+
+    /// `source`, or the plugin default if `None`.
+    pub(crate) fn source(&self, source: Option<&CameraTimeSource>) -> CameraTimeSource {
+        source.copied().unwrap_or(self.default_source)
+    }
+
+    /// Seconds elapsed this frame on `source`, or on the plugin default if `None`.
+    pub(crate) fn delta_secs(&self, source: Option<&CameraTimeSource>) -> f32 {
+        match self.source(source) {
+            CameraTimeSource::Real => self.real,
+            CameraTimeSource::Virtual => self.virtual_delta,
+            CameraTimeSource::Fixed => self.fixed,
+            CameraTimeSource::Custom => self.custom,
+        }
+    }
+}
+
+pub(crate) fn update_camera_time(
+    mut camera_time: ResMut<CameraTime>,
+    real: Res<Time<Real>>,
+    virtual_time: Res<Time<Virtual>>,
+    fixed: Res<Time<Fixed>>,
+    custom: Option<Res<Time<CameraClock>>>,
+) {
+    camera_time.real = real.delta_secs();
+    camera_time.virtual_delta = virtual_time.delta_secs();
+
+    // Fixed and custom clocks may tick any number of times per frame
+    let fixed_elapsed = fixed.elapsed();
+    camera_time.fixed = fixed_elapsed
+        .saturating_sub(camera_time.last_fixed)
+        .as_secs_f32();
+    camera_time.last_fixed = fixed_elapsed;
+
+    let custom_elapsed = custom.map_or(Duration::ZERO, |c| c.elapsed());
+    camera_time.custom = custom_elapsed
+        .saturating_sub(camera_time.last_custom)
+        .as_secs_f32();
+    camera_time.last_custom = custom_elapsed;
+}
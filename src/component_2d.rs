@@ -0,0 +1,186 @@
+use bevy::prelude::*;
+
+use crate::{
+    camera_state::ortho_half_size,
+    camera_time::{CameraTime, CameraTimeSource},
+    damping::Damping,
+    prelude::{Director, VirtualCamera},
+    target_group::TargetGroup,
+};
+
+/// Follows a target in the XY plane, for `Camera2d` based games.
+#[derive(Component, Debug, Clone)]
+pub struct Follow2d {
+    pub target: Entity,
+    /// Offset from the target to the camera center (world units)
+    pub offset: Vec2,
+    /// Area around the camera center the target can move in without the
+    /// camera following (world units)
+    pub dead_zone: Rect,
+    pub damping: Damping,
+}
+
+impl Default for Follow2d {
+    fn default() -> Self {
+        Self {
+            target: Entity::PLACEHOLDER,
+            offset: Vec2::ZERO,
+            dead_zone: Rect::default(),
+            damping: Damping::default(),
+        }
+    }
+}
+
+/// Scales an orthographic camera so every member of a group stays on screen.
+#[derive(Component, Debug, Clone)]
+pub struct ZoomToFit2d {
+    /// Entity with the [`TargetGroup`] to keep framed
+    pub group: Entity,
+    /// Space kept between the targets and the screen edge (world units)
+    pub padding: f32,
+    pub damping: Damping,
+    /// Projection scale limits
+    pub min_scale: f32,
+    pub max_scale: Option<f32>,
+}
+
+impl Default for ZoomToFit2d {
+    fn default() -> Self {
+        Self {
+            group: Entity::PLACEHOLDER,
+            padding: 0.,
+            damping: Damping::default(),
+            min_scale: 0.,
+            max_scale: None,
+        }
+    }
+}
+
+/// Snaps a real 2D camera to whole physical pixels after blending.
+///
+/// The pixel size comes from the orthographic projection's area and the
+/// camera's physical viewport, so it works with any `ScalingMode`, projection
+/// scale and window scale factor.  Perspective cameras are left alone.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct PixelSnap;
+
+pub(crate) fn follow_2d_system(
+    mut paramset: ParamSet<(
+        Query<(
+            Entity,
+            &mut Follow2d,
+            &mut Transform,
+            Option<&CameraTimeSource>,
+        )>,
+        TransformHelper,
+    )>,
+    time: Res<CameraTime>,
+) {
+    let vcams = paramset.p0().iter().map(|(e, ..)| e).collect::<Vec<_>>();
+
+    for vcam in vcams {
+        let q = paramset.p0();
+        let Ok((_, follow, ..)) = q.get(vcam) else {
+            continue;
+        };
+        let target = follow.target;
+
+        let Ok(target_tf) = paramset.p1().compute_global_transform(target) else {
+            continue;
+        };
+        let target_pos = target_tf.translation().xy();
+
+        let mut q = paramset.p0();
+        let Ok((_, mut follow, mut vcam_tf, source)) = q.get_mut(vcam) else {
+            continue;
+        };
+        let delta = time.delta_secs(source);
+
+        // Only move far enough to bring the target back to the dead zone edge
+        let to_target = target_pos + follow.offset - vcam_tf.translation.xy();
+        let clamped = to_target.clamp(follow.dead_zone.min, follow.dead_zone.max);
+        let correction = to_target - clamped;
+        if correction == Vec2::ZERO {
+            continue;
+        }
+
+        let movement = follow.damping.step(correction.extend(0.), delta);
+        let translation = vcam_tf.translation.xy() + movement.xy();
+        vcam_tf.translation = translation.extend(vcam_tf.translation.z);
+    }
+}
+
+pub(crate) fn zoom_to_fit_2d_system(
+    mut vcams: Query<(
+        &mut ZoomToFit2d,
+        &VirtualCamera,
+        &Transform,
+        &mut Projection,
+        Option<&CameraTimeSource>,
+    )>,
+    groups: Query<&TargetGroup>,
+    directors: Query<&Director>,
+    cameras: Query<&Camera>,
+    time: Res<CameraTime>,
+) {
+    for (mut zoom, vcam_component, transform, mut projection, source) in vcams.iter_mut() {
+        let Some(bounds) = groups.get(zoom.group).ok().and_then(TargetGroup::bounds) else {
+            continue;
+        };
+
+        // Viewport of the real camera, to know how much a scale of 1 shows
+        let viewport = directors
+            .get(vcam_component.director)
+            .ok()
+            .and_then(|director| cameras.get(director.camera_entity).ok())
+            .and_then(|camera| camera.logical_viewport_size());
+
+        let delta = time.delta_secs(source);
+        let Projection::Orthographic(o) = &mut *projection else {
+            continue;
+        };
+
+        // Half extents needed around the camera center
+        let center = transform.translation.xy();
+        let needed = bounds.spheres.iter().fold(Vec2::ZERO, |acc, (p, r)| {
+            acc.max((p.xy() - center).abs() + Vec2::splat(*r))
+        }) + Vec2::splat(zoom.padding);
+
+        // Half extents shown at a scale of 1
+        let half_size = ortho_half_size(o, viewport);
+
+        let mut desired_scale = (needed / half_size).max_element().max(zoom.min_scale);
+        if let Some(max_scale) = zoom.max_scale {
+            desired_scale = desired_scale.min(max_scale);
+        }
+
+        o.scale += zoom.damping.step_scalar(desired_scale - o.scale, delta);
+    }
+}
+
+pub(crate) fn pixel_snap_system(
+    mut cameras: Query<
+        (&mut Transform, &Camera, &Projection),
+        (With<PixelSnap>, Without<VirtualCamera>),
+    >,
+) {
+    for (mut transform, camera, projection) in cameras.iter_mut() {
+        let (Projection::Orthographic(o), Some(viewport)) =
+            (projection, camera.physical_viewport_size())
+        else {
+            continue;
+        };
+        if viewport.x == 0 {
+            continue;
+        }
+
+        // World units covered by one physical pixel
+        let step = o.area.width() / viewport.x as f32;
+        if step <= 0. {
+            continue;
+        }
+
+        let snapped = (transform.translation.xy() / step).round() * step;
+        transform.translation = snapped.extend(transform.translation.z);
+    }
+}
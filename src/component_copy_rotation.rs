@@ -1,27 +1,36 @@
 use bevy::prelude::*;
 
+use crate::{
+    camera_time::{CameraTime, CameraTimeSource},
+    damping::Damping,
+};
+
 #[derive(Component)]
 #[allow(dead_code)]
 pub struct CopyRotation {
     pub target: Entity,
-    pub damping: f32,
+    pub damping: Damping,
 }
 
 pub(crate) fn copy_rotation_system(
     mut paramset: ParamSet<(
-        Query<(Entity, &CopyRotation, &Projection, &mut Transform)>,
+        Query<(
+            Entity,
+            &mut CopyRotation,
+            &Projection,
+            &mut Transform,
+            Option<&CameraTimeSource>,
+        )>,
         TransformHelper,
     )>,
-    time: Res<Time<Real>>,
+    time: Res<CameraTime>,
 ) {
-    let delta = time.delta_secs();
-
     let vcams = paramset.p0().iter().map(|(e, ..)| e).collect::<Vec<_>>();
 
     for vcam in vcams {
         // Get global target position
         let q = paramset.p0();
-        let Ok((_, copy, _, _)) = q.get(vcam) else {
+        let Ok((_, copy, ..)) = q.get(vcam) else {
             continue;
         };
         let target = copy.target;
@@ -33,16 +42,14 @@ pub(crate) fn copy_rotation_system(
         let target_rot = target_pos.rotation();
 
         let mut q = paramset.p0();
-        let Ok((_, copy, _, mut cam_tf)) = q.get_mut(vcam) else {
+        let Ok((_, mut copy, _, mut cam_tf, source)) = q.get_mut(vcam) else {
             continue;
         };
+        let delta = time.delta_secs(source);
 
-        // 7) Apply damping (slerp in local space)
-        let t = if copy.damping > 0. {
-            1.0 - (-delta / copy.damping).exp()
-        } else {
-            1.0
-        };
-        cam_tf.rotation = cam_tf.rotation.slerp(target_rot, t);
+        // 7) Apply damping (per local axis)
+        cam_tf.rotation = copy
+            .damping
+            .step_rotation(cam_tf.rotation, target_rot, delta);
     }
 }
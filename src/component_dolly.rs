@@ -0,0 +1,327 @@
+use bevy::prelude::*;
+
+use crate::{
+    camera_time::{CameraTime, CameraTimeSource},
+    damping::Damping,
+};
+
+/// Samples per curve segment in the arc length table.
+const SAMPLES_PER_SEGMENT: usize = 16;
+
+/// Path for a [`DollyCart`] to ride on, in the local space of its entity.
+///
+/// The curve is measured once when the track is built, so carts can move
+/// along it by distance instead of by curve parameter, which speeds up and
+/// slows down wherever the control points are spaced unevenly.
+#[derive(Component, Debug, Clone)]
+pub struct DollyTrack {
+    curve: CubicCurve<Vec3>,
+    looped: bool,
+    // Curve positions every 1 / SAMPLES_PER_SEGMENT of a segment
+    samples: Vec<Vec3>,
+    // Distance along the track to each sample
+    lengths: Vec<f32>,
+}
+
+impl DollyTrack {
+    /// Track along any cubic curve.  A looped track joins its end back to its
+    /// start, the curve has to be closed for that to look right.
+    pub fn new(curve: CubicCurve<Vec3>, looped: bool) -> Self {
+        let count = curve.segments().len() * SAMPLES_PER_SEGMENT;
+        let samples = (0..=count)
+            .map(|i| curve.position(i as f32 / SAMPLES_PER_SEGMENT as f32))
+            .collect::<Vec<_>>();
+
+        let mut lengths = Vec::with_capacity(samples.len());
+        let mut length = 0.;
+        lengths.push(length);
+        for pair in samples.windows(2) {
+            length += pair[0].distance(pair[1]);
+            lengths.push(length);
+        }
+
+        Self {
+            curve,
+            looped,
+            samples,
+            lengths,
+        }
+    }
+
+    /// Smooth track passing through every point, `None` with fewer than two.
+    pub fn catmull_rom(points: impl IntoIterator<Item = Vec3>) -> Option<Self> {
+        let curve = CubicCardinalSpline::new_catmull_rom(points)
+            .to_curve()
+            .ok()?;
+        Some(Self::new(curve, false))
+    }
+
+    /// Like [`DollyTrack::catmull_rom`], with the last point joined back to the first.
+    pub fn catmull_rom_looped(points: impl IntoIterator<Item = Vec3>) -> Option<Self> {
+        let curve = CubicCardinalSpline::new_catmull_rom(points)
+            .to_curve_cyclic()
+            .ok()?;
+        Some(Self::new(curve, true))
+    }
+
+    /// Track made of Bezier segments, each a start point, two handles and an
+    /// end point.  `None` without any segment.
+    pub fn bezier(segments: impl IntoIterator<Item = [Vec3; 4]>) -> Option<Self> {
+        let curve = CubicBezier::new(segments).to_curve().ok()?;
+        Some(Self::new(curve, false))
+    }
+
+    pub fn curve(&self) -> &CubicCurve<Vec3> {
+        &self.curve
+    }
+
+    pub fn is_looped(&self) -> bool {
+        self.looped
+    }
+
+    /// Length of the whole track.
+    pub fn length(&self) -> f32 {
+        self.lengths.last().copied().unwrap_or(0.)
+    }
+
+    /// Wraps `distance` around a looped track, or clamps it to the ends of an open one.
+    pub fn wrap_distance(&self, distance: f32) -> f32 {
+        let length = self.length();
+        if self.looped && length > 0. {
+            distance.rem_euclid(length)
+        } else {
+            distance.clamp(0., length)
+        }
+    }
+
+    /// Curve parameter `distance` along the track.
+    fn t_at_distance(&self, distance: f32) -> f32 {
+        if self.lengths.len() < 2 {
+            return 0.;
+        }
+
+        let distance = self.wrap_distance(distance);
+        let i = self
+            .lengths
+            .partition_point(|length| *length < distance)
+            .clamp(1, self.lengths.len() - 1);
+        let (start, end) = (self.lengths[i - 1], self.lengths[i]);
+        let fraction = if end > start {
+            (distance - start) / (end - start)
+        } else {
+            0.
+        };
+        ((i - 1) as f32 + fraction) / SAMPLES_PER_SEGMENT as f32
+    }
+
+    /// Local position `distance` along the track.
+    pub fn position_at(&self, distance: f32) -> Vec3 {
+        self.curve.position(self.t_at_distance(distance))
+    }
+
+    /// Local direction of travel `distance` along the track.
+    pub fn tangent_at(&self, distance: f32) -> Vec3 {
+        self.curve
+            .velocity(self.t_at_distance(distance))
+            .normalize_or_zero()
+    }
+
+    /// Distance along the track to its point closest to a local `point`.
+    pub fn closest_distance(&self, point: Vec3) -> f32 {
+        let mut closest = (f32::INFINITY, 0.);
+        for (i, pair) in self.samples.windows(2).enumerate() {
+            let segment = pair[1] - pair[0];
+            let fraction = if segment.length_squared() > 0. {
+                ((point - pair[0]).dot(segment) / segment.length_squared()).clamp(0., 1.)
+            } else {
+                0.
+            };
+            let distance_squared = point.distance_squared(pair[0] + segment * fraction);
+            if distance_squared < closest.0 {
+                let along = self.lengths[i] + (self.lengths[i + 1] - self.lengths[i]) * fraction;
+                closest = (distance_squared, along);
+            }
+        }
+        closest.1
+    }
+
+    /// The measured points along the track, for drawing it.
+    pub(crate) fn samples(&self) -> &[Vec3] {
+        &self.samples
+    }
+}
+
+/// What moves a [`DollyCart`] along its track.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DollyCartMode {
+    /// Travels at a speed in units per second, backwards when negative.  It
+    /// stops at the ends of an open track and goes round a looped one.
+    ConstantSpeed(f32),
+    /// Sits at a position from 0 (start) to 1 (end) of the track
+    Position(f32),
+    /// Stays at the point of the track closest to an entity
+    TrackTarget(Entity),
+}
+
+/// How a [`DollyCart`] turns the camera.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DollyRotation {
+    /// Leaves the rotation alone, e.g. for a [`LookAtTarget`](crate::prelude::LookAtTarget)
+    #[default]
+    Unchanged,
+    /// Faces the direction of travel
+    Path,
+    /// Faces the direction of travel but stays level, only turning around the track's up
+    PathYaw,
+}
+
+/// Moves the camera along a [`DollyTrack`], like a camera dolly on rails.
+///
+/// Where the cart is on its track lives in the [`DollyCartState`] added with it.
+#[derive(Component, Debug, Clone)]
+#[require(DollyCartState)]
+pub struct DollyCart {
+    /// Entity with a [`DollyTrack`]
+    pub track: Entity,
+    pub mode: DollyCartMode,
+    pub rotation: DollyRotation,
+    /// Damping of the movement along the track, unused at constant speed
+    pub damping: Damping,
+    pub rotation_damping: Damping,
+    /// Distance along the track the cart starts at
+    pub start: f32,
+}
+
+impl Default for DollyCart {
+    fn default() -> Self {
+        Self {
+            track: Entity::PLACEHOLDER,
+            mode: DollyCartMode::ConstantSpeed(1.),
+            rotation: DollyRotation::Unchanged,
+            damping: Damping::new(0.3),
+            rotation_damping: Damping::new(0.2),
+            start: 0.,
+        }
+    }
+}
+
+/// Where a [`DollyCart`] is on its track.
+#[derive(Component, Debug, Clone, Default)]
+pub struct DollyCartState {
+    distance: Option<f32>,
+}
+
+impl DollyCartState {
+    /// Distance along the track the cart is at, `None` before it first moved.
+    pub fn distance(&self) -> Option<f32> {
+        self.distance
+    }
+
+    /// Puts the cart back at [`DollyCart::start`].
+    pub fn reset(&mut self) {
+        self.distance = None;
+    }
+}
+
+pub(crate) fn dolly_cart_system(
+    mut paramset: ParamSet<(
+        Query<(
+            Entity,
+            &mut DollyCart,
+            &mut DollyCartState,
+            &mut Transform,
+            Option<&CameraTimeSource>,
+        )>,
+        TransformHelper,
+    )>,
+    tracks: Query<&DollyTrack>,
+    time: Res<CameraTime>,
+) {
+    let vcams = paramset.p0().iter().map(|(e, ..)| e).collect::<Vec<_>>();
+
+    for vcam in vcams {
+        let q = paramset.p0();
+        let Ok((_, cart, ..)) = q.get(vcam) else {
+            continue;
+        };
+        let (track_entity, mode) = (cart.track, cart.mode);
+        let Ok(track) = tracks.get(track_entity) else {
+            continue;
+        };
+
+        // World transforms of the track and the tracked entity
+        let helper = paramset.p1();
+        let Ok(track_tf) = helper.compute_global_transform(track_entity) else {
+            continue;
+        };
+        let target = match mode {
+            DollyCartMode::TrackTarget(target) => {
+                let Ok(target_tf) = helper.compute_global_transform(target) else {
+                    continue;
+                };
+                Some(target_tf.translation())
+            }
+            _ => None,
+        };
+
+        let mut q = paramset.p0();
+        let Ok((_, mut cart, mut state, mut vcam_tf, source)) = q.get_mut(vcam) else {
+            continue;
+        };
+        let delta = time.delta_secs(source);
+        let current = state
+            .distance
+            .unwrap_or_else(|| track.wrap_distance(cart.start));
+
+        let distance = match mode {
+            DollyCartMode::ConstantSpeed(speed) => track.wrap_distance(current + speed * delta),
+            DollyCartMode::Position(position) => {
+                let desired = track.wrap_distance(position * track.length());
+                step_along(track, &mut cart.damping, current, desired, delta)
+            }
+            DollyCartMode::TrackTarget(_) => {
+                let local = track_tf
+                    .affine()
+                    .inverse()
+                    .transform_point3(target.unwrap_or_default());
+                let desired = track.closest_distance(local);
+                step_along(track, &mut cart.damping, current, desired, delta)
+            }
+        };
+        state.distance = Some(distance);
+
+        vcam_tf.translation = track_tf.transform_point(track.position_at(distance));
+
+        let up = track_tf.up();
+        let mut forward = track_tf
+            .affine()
+            .transform_vector3(track.tangent_at(distance));
+        if cart.rotation == DollyRotation::PathYaw {
+            forward = forward.reject_from(*up);
+        }
+        if cart.rotation == DollyRotation::Unchanged || forward.length_squared() <= f32::EPSILON {
+            continue;
+        }
+
+        let desired = Transform::default().looking_to(forward, up).rotation;
+        let current = vcam_tf.rotation;
+        vcam_tf.rotation = cart.rotation_damping.step_rotation(current, desired, delta);
+    }
+}
+
+/// Damped distance from `current` towards `desired`, going the short way
+/// round a looped track.
+fn step_along(
+    track: &DollyTrack,
+    damping: &mut Damping,
+    current: f32,
+    desired: f32,
+    delta: f32,
+) -> f32 {
+    let mut offset = desired - current;
+    let length = track.length();
+    if track.is_looped() && length > 0. {
+        offset = (offset + length * 0.5).rem_euclid(length) - length * 0.5;
+    }
+    track.wrap_distance(current + damping.step_scalar(offset, delta))
+}
@@ -1,37 +1,84 @@
 use bevy::prelude::*;
-use smallvec::SmallVec;
+
+use crate::{
+    camera_time::{CameraTime, CameraTimeSource},
+    damping::Damping,
+    look_ahead::LookAhead,
+    target_group::TargetGroup,
+    world_to_ndc, DeadZone,
+};
 
 #[derive(Component)]
 #[allow(dead_code)]
 pub struct FollowTarget {
     pub target: Entity,
     pub offset: Vec3,
-    pub damping: f32,
+    pub damping: Damping,
+    /// Follow where the target is heading instead of where it is
+    pub look_ahead: Option<LookAhead>,
 }
 
 #[derive(Component)]
 #[allow(dead_code)]
 pub struct FollowGroup {
-    pub targets: SmallVec<[Entity; 8]>,
+    /// Entity with a [`TargetGroup`]
+    pub group: Entity,
+    pub offset: Vec3,
+    pub damping: Damping,
+}
+
+/// Moves the camera so the target lands at a chosen screen position and
+/// distance, without rotating it.
+///
+/// Zones are in NDC, around `screen_position`.  Inside the dead zone the camera
+/// doesn't track the target sideways, inside the soft zone it catches up with
+/// damping, and the target is never allowed outside the soft zone.  Damping is
+/// per camera local axis.
+#[derive(Component, Debug, Clone)]
+pub struct FollowFraming {
+    pub target: Entity,
     pub offset: Vec3,
-    pub damping: f32,
+    /// Where the target sits on screen (NDC)
+    pub screen_position: Vec2,
+    /// Distance from the camera to the target along the camera forward
+    pub distance: f32,
+    pub dead_zone: DeadZone,
+    pub soft_zone: DeadZone,
+    pub damping: Damping,
+}
+
+impl Default for FollowFraming {
+    fn default() -> Self {
+        Self {
+            target: Entity::PLACEHOLDER,
+            offset: Vec3::ZERO,
+            screen_position: Vec2::ZERO,
+            distance: 10.,
+            dead_zone: DeadZone::ZERO,
+            soft_zone: DeadZone::FULL,
+            damping: Damping::new(0.5),
+        }
+    }
 }
 
 pub(crate) fn follow_target_system(
     mut paramset: ParamSet<(
-        Query<(Entity, &FollowTarget, &mut Transform)>,
+        Query<(
+            Entity,
+            &mut FollowTarget,
+            &mut Transform,
+            Option<&CameraTimeSource>,
+        )>,
         TransformHelper,
     )>,
-    time: Res<Time<Real>>,
+    time: Res<CameraTime>,
 ) {
-    let delta = time.delta_secs();
-
     let vcams = paramset.p0().iter().map(|(e, ..)| e).collect::<Vec<_>>();
 
     for vcam in vcams {
         // Determine target world position
         let q = paramset.p0();
-        let Ok((_, follow, _)) = q.get(vcam) else {
+        let Ok((_, follow, ..)) = q.get(vcam) else {
             continue;
         };
         let target = follow.target;
@@ -42,81 +89,138 @@ pub(crate) fn follow_target_system(
         };
 
         let mut q = paramset.p0();
-        let Ok((_, follow, mut vcam_tf)) = q.get_mut(vcam) else {
+        let Ok((_, mut follow, mut vcam_tf, source)) = q.get_mut(vcam) else {
             continue;
         };
+        let delta = time.delta_secs(source);
+
+        let mut target_pos = target_tf.translation();
+        if let Some(look_ahead) = &mut follow.look_ahead {
+            target_pos = look_ahead.predict(target_pos, delta);
+        }
 
         // Handle weirdness on target.  Otherwise follow is permanently broken
         if vcam_tf.translation.is_nan() || !vcam_tf.translation.is_finite() {
-            vcam_tf.translation = target_tf.translation();
+            vcam_tf.translation = target_pos;
             continue;
         }
 
         // Apply to local transform
-        let s = if follow.damping > 0. {
-            1.0 - (-delta / follow.damping).exp()
-        } else {
-            1.0
-        };
-        vcam_tf.translation = vcam_tf.translation.lerp(
-            target_tf.translation() + vcam_tf.rotation * follow.offset,
-            s,
-        );
+        let desired = target_pos + vcam_tf.rotation * follow.offset;
+        vcam_tf.translation =
+            follow
+                .damping
+                .step_translation(vcam_tf.translation, desired, vcam_tf.rotation, delta);
     }
 }
 
 pub(crate) fn follow_group_system(
+    mut vcams: Query<(&mut FollowGroup, &mut Transform, Option<&CameraTimeSource>)>,
+    groups: Query<&TargetGroup>,
+    time: Res<CameraTime>,
+) {
+    for (mut follow, mut vcam_tf, source) in vcams.iter_mut() {
+        // Determine group world position
+        let Some(bounds) = groups.get(follow.group).ok().and_then(TargetGroup::bounds) else {
+            continue;
+        };
+        let target_pos = bounds.center;
+        let delta = time.delta_secs(source);
+
+        // Handle weirdness on target.  Otherwise follow is permanently broken
+        if vcam_tf.translation.is_nan() || !vcam_tf.translation.is_finite() {
+            vcam_tf.translation = target_pos;
+            continue;
+        }
+
+        // Apply to local transform
+        let desired = target_pos + follow.offset;
+        vcam_tf.translation =
+            follow
+                .damping
+                .step_translation(vcam_tf.translation, desired, vcam_tf.rotation, delta);
+    }
+}
+
+pub(crate) fn follow_framing_system(
     mut paramset: ParamSet<(
-        Query<(Entity, &FollowGroup, &mut Transform)>,
+        Query<(
+            Entity,
+            &mut FollowFraming,
+            &Projection,
+            &mut Transform,
+            Option<&CameraTimeSource>,
+        )>,
         TransformHelper,
     )>,
-    time: Res<Time<Real>>,
+    time: Res<CameraTime>,
 ) {
-    let delta = time.delta_secs();
-
     let vcams = paramset.p0().iter().map(|(e, ..)| e).collect::<Vec<_>>();
 
     for vcam in vcams {
         // Determine target world position
         let q = paramset.p0();
-        let Ok((_, follow, _)) = q.get(vcam) else {
+        let Ok((_, follow, ..)) = q.get(vcam) else {
             continue;
         };
-
-        let targets = follow.targets.clone();
-        let count = targets.len();
-        if count == 0 {
-            return;
-        }
+        let target = follow.target;
+        let offset = follow.offset;
 
         let helper = paramset.p1();
-        let mut sum = Vec3::ZERO;
-
-        for target in targets {
-            let Ok(target_pos) = helper.compute_global_transform(target) else {
-                continue;
-            };
-            sum += target_pos.translation();
-        }
-        let target_pos = sum / count as f32;
+        let Ok(target_tf) = helper.compute_global_transform(target) else {
+            continue;
+        };
+        let target_pos = target_tf.translation() + offset;
 
         let mut q = paramset.p0();
-        let Ok((_, follow, mut vcam_tf)) = q.get_mut(vcam) else {
+        let Ok((_, mut follow, projection, mut vcam_tf, source)) = q.get_mut(vcam) else {
             continue;
         };
+        let delta = time.delta_secs(source);
 
-        // Handle weirdness on target.  Otherwise follow is permanently broken
-        if vcam_tf.translation.is_nan() || !vcam_tf.translation.is_finite() {
-            vcam_tf.translation = target_pos;
+        // Target in camera space, and where it sits on screen
+        let local = vcam_tf.rotation.inverse() * (target_pos - vcam_tf.translation);
+        let depth = -local.z;
+        if depth <= 0. {
             continue;
         }
-
-        // Apply to local transform
-        let t = if follow.damping > 0. {
-            1.0 - (-delta / follow.damping).exp()
-        } else {
-            1.0
+        let ndc = world_to_ndc(target_pos, &vcam_tf, projection);
+
+        // Camera space offset of a point at the target's depth that shows up at `screen`
+        let lateral = |screen: Vec2| match projection {
+            Projection::Perspective(p) => {
+                let half_height = (p.fov * 0.5).tan() * depth;
+                Vec2::new(
+                    screen.x * half_height * p.aspect_ratio,
+                    screen.y * half_height,
+                )
+            }
+            // Same extents the projection matrix uses in `world_to_ndc`
+            Projection::Orthographic(o) => o.area.center() + screen * o.area.half_size(),
+            Projection::Custom(_) => Vec2::ZERO,
         };
-        vcam_tf.translation = vcam_tf.translation.lerp(target_pos + follow.offset, t);
+
+        // Camera movement (camera space) that would frame the target perfectly
+        let mut wanted =
+            (local.xy() - lateral(follow.screen_position)).extend(local.z + follow.distance);
+
+        // Zones are around the screen position
+        let framed = ndc - follow.screen_position;
+        if follow.dead_zone.contains(framed) {
+            wanted.x = 0.;
+            wanted.y = 0.;
+        }
+
+        // Hard limit: bring the target back inside the soft zone immediately
+        let mut hard = Vec3::ZERO;
+        if !follow.soft_zone.contains(framed) {
+            let edge = follow.screen_position + follow.soft_zone.clamp(framed);
+            hard = (local.xy() - lateral(edge)).extend(0.);
+            wanted -= hard;
+        }
+
+        // Apply damping per axis
+        let movement = vcam_tf.rotation * (hard + follow.damping.step(wanted, delta));
+        vcam_tf.translation += movement;
     }
 }
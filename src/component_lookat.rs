@@ -1,40 +1,75 @@
 use bevy::prelude::*;
 
-use crate::{world_to_ndc, DeadZone};
-
+use crate::{
+    camera_time::{CameraTime, CameraTimeSource},
+    damping::Damping,
+    look_ahead::LookAhead,
+    target_group::TargetGroup,
+    world_to_ndc, DeadZone,
+};
+
+/// Rotates the camera to frame a target.
+///
+/// Zones are in NDC, around `aim`.  Inside the dead zone the camera holds
+/// still, inside the soft zone it turns towards `aim` with damping, and the
+/// target is never allowed outside the soft zone.
 #[derive(Component)]
 #[allow(dead_code)]
 pub struct LookAtTarget {
     pub target: Entity,
     pub offset: Vec3,
     pub dead_zone: DeadZone,
-    pub damping: f32,
+    pub soft_zone: DeadZone,
+    /// Screen position the target settles at, e.g. `Vec2::new(-1. / 3., 1. / 3.)` for rule of thirds
+    pub aim: Vec2,
+    pub damping: Damping,
+    /// Aim where the target is heading instead of where it is
+    pub look_ahead: Option<LookAhead>,
+}
+
+impl Default for LookAtTarget {
+    fn default() -> Self {
+        Self {
+            target: Entity::PLACEHOLDER,
+            offset: Vec3::ZERO,
+            dead_zone: DeadZone::ZERO,
+            soft_zone: DeadZone::FULL,
+            aim: Vec2::ZERO,
+            damping: Damping::default(),
+            look_ahead: None,
+        }
+    }
 }
 
 #[derive(Component)]
 #[allow(dead_code)]
 pub struct LookAtGroup {
-    pub targets: Vec<Entity>,
+    /// Entity with a [`TargetGroup`]
+    pub group: Entity,
     pub offset: Vec3,
     pub dead_zone: DeadZone,
-    pub damping: f32,
+    pub damping: Damping,
 }
 
 pub(crate) fn look_at_system(
     mut paramset: ParamSet<(
-        Query<(Entity, &LookAtTarget, &Projection, &mut Transform)>,
+        Query<(
+            Entity,
+            &mut LookAtTarget,
+            &Projection,
+            &mut Transform,
+            Option<&CameraTimeSource>,
+        )>,
         TransformHelper,
     )>,
-    time: Res<Time<Real>>,
+    time: Res<CameraTime>,
 ) {
-    let delta = time.delta_secs();
-
     let vcams = paramset.p0().iter().map(|(e, ..)| e).collect::<Vec<_>>();
 
     for vcam in vcams {
         // Get global target position
         let q = paramset.p0();
-        let Ok((_, look_at, _, _)) = q.get(vcam) else {
+        let Ok((_, look_at, ..)) = q.get(vcam) else {
             continue;
         };
         let target = look_at.target;
@@ -47,9 +82,14 @@ pub(crate) fn look_at_system(
         let target_pos = target_pos.translation() + offset;
 
         let mut q = paramset.p0();
-        let Ok((_, look_at, cam_proj, mut cam_tf)) = q.get_mut(vcam) else {
+        let Ok((_, mut look_at, cam_proj, mut cam_tf, source)) = q.get_mut(vcam) else {
             continue;
         };
+        let delta = time.delta_secs(source);
+        let target_pos = match &mut look_at.look_ahead {
+            Some(look_ahead) => look_ahead.predict(target_pos, delta),
+            None => target_pos,
+        };
 
         // 3) Compute screen-space position in [0,1] (X = 0 left -> 1 right, Y = 0 bottom -> 1 top)
         let screen_pos = match cam_proj {
@@ -70,69 +110,71 @@ pub(crate) fn look_at_system(
             }
         };
 
-        let dead_zone = look_at.dead_zone;
-        // 5) If target inside dead zone -> do nothing
-        if screen_pos.x >= dead_zone.xmin
-            && screen_pos.x <= dead_zone.xmax
-            && screen_pos.y >= dead_zone.ymin
-            && screen_pos.y <= dead_zone.ymax
-        {
+        // 5) If target inside dead zone (around the aim point) -> do nothing
+        let framed = screen_pos - look_at.aim;
+        if look_at.dead_zone.contains(framed) {
             continue;
         }
 
-        // 6) Otherwise compute desired world rotation (look at target from vcam world pos)
-        let desired_rot = Quat::look_at_rh(cam_tf.translation, target_pos, Vec3::Y).inverse();
+        // 6) Hard limit: pull the target back to the soft zone edge immediately
+        let mut rotation = cam_tf.rotation;
+        if !look_at.soft_zone.contains(framed) {
+            let limit = look_at.aim + look_at.soft_zone.clamp(framed);
+            rotation = frame_rotation(cam_tf.translation, target_pos, limit, cam_proj);
+        }
 
-        // 7) Apply damping (slerp in local space)
-        let t = if look_at.damping > 0. {
-            1.0 - (-delta / look_at.damping).exp()
-        } else {
-            1.0
-        };
-        cam_tf.rotation = cam_tf.rotation.slerp(desired_rot, t);
+        // 7) Compute desired world rotation putting the target at the aim point
+        let desired_rot = frame_rotation(cam_tf.translation, target_pos, look_at.aim, cam_proj);
+
+        // 8) Apply damping (per local axis)
+        cam_tf.rotation = look_at.damping.step_rotation(rotation, desired_rot, delta);
     }
 }
 
-pub(crate) fn look_at_group_system(
-    mut paramset: ParamSet<(
-        Query<(Entity, &LookAtGroup, &Projection, &mut Transform)>,
-        TransformHelper,
-    )>,
-    time: Res<Time<Real>>,
-) {
-    let delta = time.delta_secs();
-
-    let vcams = paramset.p0().iter().map(|(e, ..)| e).collect::<Vec<_>>();
-
-    for vcam in vcams {
-        // Get global target position
-        let q = paramset.p0();
-        let Ok((_, look_at, _, _)) = q.get(vcam) else {
-            continue;
-        };
-        let offset = look_at.offset;
-
-        let targets = look_at.targets.clone();
-        let count = targets.len();
-        if count == 0 {
-            continue;
+/// Rotation for a camera at `eye` that puts `target` at screen position `ndc`.
+fn frame_rotation(eye: Vec3, target: Vec3, ndc: Vec2, projection: &Projection) -> Quat {
+    let look = Quat::look_at_rh(eye, target, Vec3::Y).inverse();
+
+    // View space ray through the screen position
+    let ray = match projection {
+        Projection::Perspective(p) => {
+            let half_height = (p.fov * 0.5).tan();
+            Vec3::new(
+                ndc.x * half_height * p.aspect_ratio,
+                ndc.y * half_height,
+                -1.0,
+            )
         }
-
-        let helper = paramset.p1();
-        let mut sum = Vec3::ZERO;
-
-        for target in targets {
-            let Ok(target_pos) = helper.compute_global_transform(target) else {
-                continue;
-            };
-            sum += target_pos.translation();
+        Projection::Orthographic(o) => {
+            // Same approximation as the screen position, o.scale maps to NDC 1
+            Vec3::new(ndc.x * o.scale, ndc.y * o.scale, -eye.distance(target))
         }
-        let target_pos = sum / count as f32 + offset;
+        Projection::Custom(_) => Vec3::NEG_Z,
+    };
 
-        let mut q = paramset.p0();
-        let Ok((_, look_at, cam_proj, mut cam_tf)) = q.get_mut(vcam) else {
+    let Some(ray) = ray.try_normalize() else {
+        return look;
+    };
+    look * Quat::from_rotation_arc(ray, Vec3::NEG_Z)
+}
+
+pub(crate) fn look_at_group_system(
+    mut vcams: Query<(
+        &mut LookAtGroup,
+        &Projection,
+        &mut Transform,
+        Option<&CameraTimeSource>,
+    )>,
+    groups: Query<&TargetGroup>,
+    time: Res<CameraTime>,
+) {
+    for (mut look_at, cam_proj, mut cam_tf, source) in vcams.iter_mut() {
+        // Get group world position
+        let Some(bounds) = groups.get(look_at.group).ok().and_then(TargetGroup::bounds) else {
             continue;
         };
+        let target_pos = bounds.center + look_at.offset;
+        let delta = time.delta_secs(source);
 
         // 3) Compute screen-space position in [0,1] (X = 0 left -> 1 right, Y = 0 bottom -> 1 top)
         let screen_pos = match cam_proj {
@@ -153,25 +195,17 @@ pub(crate) fn look_at_group_system(
             }
         };
 
-        let dead_zone = look_at.dead_zone;
         // 5) If target inside dead zone -> do nothing
-        if screen_pos.x >= dead_zone.xmin
-            && screen_pos.x <= dead_zone.xmax
-            && screen_pos.y >= dead_zone.ymin
-            && screen_pos.y <= dead_zone.ymax
-        {
+        if look_at.dead_zone.contains(screen_pos) {
             continue;
         }
 
         // 6) Otherwise compute desired world rotation (look at target from vcam world pos)
         let desired_rot = Quat::look_at_rh(cam_tf.translation, target_pos, Vec3::Y).inverse();
 
-        // 7) Apply damping (slerp in local space)
-        let t = if look_at.damping > 0. {
-            1.0 - (-delta / look_at.damping).exp()
-        } else {
-            1.0
-        };
-        cam_tf.rotation = cam_tf.rotation.slerp(desired_rot, t);
+        // 7) Apply damping (per local axis)
+        cam_tf.rotation = look_at
+            .damping
+            .step_rotation(cam_tf.rotation, desired_rot, delta);
     }
 }
@@ -1,5 +1,10 @@
 use bevy::prelude::*;
 
+use crate::{
+    camera_time::{CameraTime, CameraTimeSource},
+    damping::Damping,
+};
+
 #[derive(Component, Debug, Clone)]
 pub struct OrbitArm {
     /// The entity to orbit around
@@ -16,7 +21,7 @@ pub struct OrbitArm {
     pub pitch: f32, // up/down
 
     /// Optional damping (smooth movement)
-    pub damping: f32,
+    pub damping: Damping,
 
     /// Clamps to prevent flipping over
     pub min_pitch: f32,
@@ -31,7 +36,7 @@ impl Default for OrbitArm {
             radius: 5.0,
             yaw: 0.0,
             pitch: 0.3,
-            damping: 0.125,
+            damping: Damping::new(0.125),
             min_pitch: -1.4,
             max_pitch: 1.4,
         }
@@ -39,19 +44,22 @@ impl Default for OrbitArm {
 }
 
 pub fn orbit_camera_system(
-    time: Res<Time<Real>>,
+    time: Res<CameraTime>,
     mut paramset: ParamSet<(
-        Query<(Entity, &mut OrbitArm, &mut Transform)>,
+        Query<(
+            Entity,
+            &mut OrbitArm,
+            &mut Transform,
+            Option<&CameraTimeSource>,
+        )>,
         TransformHelper,
     )>,
 ) {
-    let delta = time.delta_secs();
-
     let vcams = paramset.p0().iter().map(|(e, ..)| e).collect::<Vec<_>>();
 
     for vcam in vcams {
         let q = paramset.p0();
-        let Ok((_, orbit, _)) = q.get(vcam) else {
+        let Ok((_, orbit, ..)) = q.get(vcam) else {
             continue;
         };
         let target = orbit.target;
@@ -63,9 +71,10 @@ pub fn orbit_camera_system(
 
         // Clamp pitch to valid range
         let mut q = paramset.p0();
-        let Ok((_, mut orbit, mut transform)) = q.get_mut(vcam) else {
+        let Ok((_, mut orbit, mut transform, source)) = q.get_mut(vcam) else {
             continue;
         };
+        let delta = time.delta_secs(source);
         orbit.pitch = orbit.pitch.clamp(orbit.min_pitch, orbit.max_pitch);
 
         // Compute desired position in spherical coordinates
@@ -81,15 +90,12 @@ pub fn orbit_camera_system(
         let offset_world = transform.rotation * orbit.offset;
         let desired_pos = desired_pos + offset_world;
 
-        // Damping factor (smooth movement)
-        let t = if orbit.damping > 0.0 {
-            1.0 - (-delta / orbit.damping).exp()
-        } else {
-            1.0
-        };
-
         // Smoothly move camera
-        transform.translation = transform.translation.lerp(desired_pos, t);
+        let rotation = transform.rotation;
+        transform.translation =
+            orbit
+                .damping
+                .step_translation(transform.translation, desired_pos, rotation, delta);
 
         // Always look at target
         transform.look_at(target_pos + offset_world, Vec3::Y);
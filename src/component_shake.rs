@@ -1,6 +1,58 @@
-use bevy::prelude::*;
+use std::{f32::consts::TAU, time::Duration};
 
-#[derive(Component, Debug, Clone, Default)]
+use bevy::{ecs::query::QueryFilter, platform::collections::HashMap, prelude::*};
+use serde::Deserialize;
+
+use crate::{
+    camera_time::{CameraTime, CameraTimeSource},
+    noise,
+    shake_profile::{ProfileShake, ShakeProfile},
+};
+
+/// Shape of the signal each shake axis follows.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize)]
+pub enum ShakeWaveform {
+    /// A sine wave, regular and repeating
+    #[default]
+    Sine,
+    /// Fractal Perlin noise.  Each of the `octaves` has `lacunarity` times the
+    /// frequency and `persistence` times the amplitude of the previous one.
+    Noise {
+        octaves: u32,
+        lacunarity: f32,
+        persistence: f32,
+    },
+}
+
+impl ShakeWaveform {
+    /// Three octaves of noise, a good handheld or explosion look.
+    pub const NOISE: Self = Self::Noise {
+        octaves: 3,
+        lacunarity: 2.,
+        persistence: 0.5,
+    };
+
+    /// Value in [-1, 1] after `cycles` periods.  `axis` picks an independent
+    /// signal for noise.
+    fn sample(&self, cycles: f32, axis: usize) -> f32 {
+        match *self {
+            Self::Sine => (cycles * TAU).sin(),
+            Self::Noise {
+                octaves,
+                lacunarity,
+                persistence,
+            } => noise::fractal(
+                cycles + axis as f32 * 1000.,
+                octaves,
+                lacunarity,
+                persistence,
+            ),
+        }
+    }
+}
+
+/// One shake instance, mixed with the others in a [`CameraShake`].
+#[derive(Debug, Clone, Default)]
 pub struct Shake {
     /// Total duration of the shake
     pub timer: Timer,
@@ -12,92 +64,228 @@ pub struct Shake {
     pub translation_frequency: Vec3,
     /// Frequency of rotation shake (Hz)
     pub rotation_frequency: Vec3,
+    pub waveform: ShakeWaveform,
     /// Damping factor (0 = instant stop, 1 = full fade over time)
     pub damping: f32,
     /// Optional seed for reproducible shake (affects phase)
     pub seed: f32,
-    /// Transform when finished (cached automatically)
-    pub original_transform: Option<Transform>,
 }
 
+impl Shake {
+    /// Translation and rotation (XYZ euler) offsets `elapsed` seconds in, at
+    /// full intensity times `magnitude`.
+    pub(crate) fn sample(&self, elapsed: f32, magnitude: f32) -> (Vec3, Vec3) {
+        let elapsed = elapsed + self.seed;
+        let wave = |frequency: Vec3, intensity: Vec3, offset: usize| {
+            Vec3::from_array(std::array::from_fn(|axis| {
+                self.waveform
+                    .sample(elapsed * frequency[axis], axis + offset)
+                    * intensity[axis]
+                    * magnitude
+            }))
+        };
+
+        (
+            wave(self.translation_frequency, self.translation_intensity, 0),
+            wave(self.rotation_frequency, self.rotation_intensity, 3),
+        )
+    }
+}
+
+/// Shake layered on top of a camera's transform.
+///
+/// On a virtual camera the offset is added after every body and aim component
+/// ran and taken back out before they run again, so the camera keeps
+/// following while it shakes.  On a real camera it is added after blending,
+/// shaking whatever the director shows.
+#[derive(Component, Debug, Clone, Default)]
+#[require(ShakeState)]
+pub struct CameraShake {
+    /// Shakes in progress, mixed together
+    pub shakes: Vec<Shake>,
+}
+
+/// Profiles playing on a [`CameraShake`] and the offset it applied last.
+#[derive(Component, Debug, Clone, Default)]
+pub(crate) struct ShakeState {
+    profiles: Vec<ProfileShake>,
+    applied: Option<(Vec3, Quat)>,
+}
+
+/// Shake driven by trauma, a value from 0 to 1 raised by
+/// [`AddCameraShake::Trauma`] and decaying over time.
+///
+/// The shake magnitude is trauma to the power of `exponent`, so small hits
+/// barely register and repeated ones stack up instead of restarting.
+#[derive(Component, Debug, Clone)]
+#[require(CameraShake)]
+pub struct Trauma {
+    /// Shake at full trauma, its `timer` and `damping` are unused
+    pub shake: Shake,
+    /// Trauma lost per second
+    pub decay: f32,
+    pub exponent: f32,
+    /// Current trauma
+    pub trauma: f32,
+    pub(crate) elapsed: f32,
+}
+
+impl Trauma {
+    pub fn new(shake: Shake) -> Self {
+        Self {
+            shake,
+            decay: 1.,
+            exponent: 2.,
+            trauma: 0.,
+            elapsed: 0.,
+        }
+    }
+}
+
+/// Shakes a virtual camera, or a real camera to shake after blending.
 #[derive(Message)]
-pub struct AddCameraShake {
-    pub vcam_entity: Entity,
-    pub camera_shake: Shake,
+pub enum AddCameraShake {
+    /// Adds a shake, mixed with any already in progress
+    Shake { camera: Entity, camera_shake: Shake },
+    /// Adds trauma to a camera with a [`Trauma`] component
+    Trauma { camera: Entity, amount: f32 },
+    /// Plays a [`ShakeProfile`] asset with its intensity times `scale`.  It
+    /// starts once the asset is loaded.
+    Profile {
+        camera: Entity,
+        profile: Handle<ShakeProfile>,
+        scale: f32,
+    },
 }
 
 pub(crate) fn add_shake(
     mut reader: MessageReader<AddCameraShake>,
     mut commands: Commands,
-    mut query: Query<(&mut Transform, &mut Shake)>,
+    mut shakes: Query<(&mut CameraShake, &mut ShakeState)>,
+    mut traumas: Query<&mut Trauma>,
 ) {
-    for AddCameraShake {
-        vcam_entity,
-        camera_shake,
-    } in reader.read()
-    {
-        if let Ok((mut transform, shake)) = query.get_mut(*vcam_entity) {
-            if let Some(original) = shake.original_transform {
-                *transform = original;
+    // Cameras that don't shake yet get all of this frame's shakes at once
+    let mut added = HashMap::<Entity, (CameraShake, ShakeState)>::default();
+
+    for message in reader.read() {
+        match message {
+            AddCameraShake::Shake {
+                camera,
+                camera_shake,
+            } => match shakes.get_mut(*camera) {
+                Ok((mut shake, _)) => shake.shakes.push(camera_shake.clone()),
+                Err(_) => added
+                    .entry(*camera)
+                    .or_default()
+                    .0
+                    .shakes
+                    .push(camera_shake.clone()),
+            },
+            AddCameraShake::Profile {
+                camera,
+                profile,
+                scale,
+            } => {
+                let instance = ProfileShake::new(profile.clone(), *scale);
+                match shakes.get_mut(*camera) {
+                    Ok((_, mut state)) => state.profiles.push(instance),
+                    Err(_) => added.entry(*camera).or_default().1.profiles.push(instance),
+                }
+            }
+            AddCameraShake::Trauma { camera, amount } => {
+                if let Ok(mut trauma) = traumas.get_mut(*camera) {
+                    trauma.trauma = (trauma.trauma + amount).clamp(0., 1.);
+                }
             }
-            commands.entity(*vcam_entity).remove::<Shake>();
         }
-        commands.entity(*vcam_entity).insert(camera_shake.clone());
+    }
+
+    for (entity, shake) in added {
+        if let Ok(mut entity) = commands.get_entity(entity) {
+            entity.insert(shake);
+        }
     }
 }
 
-pub(crate) fn camera_shake_system(
-    mut commands: Commands,
-    time: Res<Time>,
-    mut query: Query<(Entity, &mut Transform, &mut Shake)>,
-) {
-    for (entity, mut tf, mut shake) in query.iter_mut() {
-        // Store original transform on first frame
-        if shake.original_transform.is_none() {
-            shake.original_transform = Some(*tf);
+/// Takes last frame's shake back out before anything moves the cameras.
+pub(crate) fn remove_shake_system(mut query: Query<(&mut Transform, &mut ShakeState)>) {
+    for (mut tf, mut state) in query.iter_mut() {
+        if let Some((translation, rotation)) = state.applied.take() {
+            tf.translation -= translation;
+            tf.rotation = rotation.inverse() * tf.rotation;
         }
+    }
+}
 
-        shake.timer.tick(time.delta());
+pub(crate) fn camera_shake_system<F: QueryFilter>(
+    time: Res<CameraTime>,
+    profiles: Option<Res<Assets<ShakeProfile>>>,
+    asset_server: Option<Res<AssetServer>>,
+    mut query: Query<
+        (
+            &mut Transform,
+            &mut CameraShake,
+            &mut ShakeState,
+            Option<&mut Trauma>,
+            Option<&CameraTimeSource>,
+        ),
+        F,
+    >,
+) {
+    for (mut tf, mut shake, mut state, trauma, source) in query.iter_mut() {
+        let delta = time.delta_secs(source);
+        let mut translation = Vec3::ZERO;
+        let mut rotation = Vec3::ZERO;
 
-        // Remove shake when timer finishes
-        if shake.timer.is_finished() {
-            if let Some(original) = shake.original_transform.take() {
-                *tf = original;
+        // Mix every shake in progress, dropping finished ones
+        shake.shakes.retain_mut(|instance| {
+            instance.timer.tick(Duration::from_secs_f32(delta));
+            if instance.timer.is_finished() {
+                return false;
             }
-            commands.entity(entity).remove::<Shake>();
+
+            let damping_factor = 1.0 - instance.timer.fraction() * instance.damping;
+            let (t, r) = instance.sample(instance.timer.elapsed_secs(), damping_factor);
+            translation += t;
+            rotation += r;
+            true
+        });
+
+        // Profiles wait for their asset, then play until their envelope ends.
+        // Ones whose asset failed to load are dropped.
+        state.profiles.retain_mut(|instance| {
+            let Some(profile) = profiles.as_ref().and_then(|p| p.get(&instance.profile)) else {
+                return !asset_server
+                    .as_ref()
+                    .is_some_and(|server| server.load_state(&instance.profile).is_failed());
+            };
+            let Some((t, r)) = instance.step(profile, delta) else {
+                return false;
+            };
+            translation += t;
+            rotation += r;
+            true
+        });
+
+        if let Some(mut trauma) = trauma.filter(|trauma| trauma.trauma > 0.) {
+            trauma.elapsed += delta;
+
+            let magnitude = trauma.trauma.powf(trauma.exponent);
+            let (t, r) = trauma.shake.sample(trauma.elapsed, magnitude);
+            translation += t;
+            rotation += r;
+
+            trauma.trauma = (trauma.trauma - trauma.decay * delta).max(0.);
+        }
+
+        if translation == Vec3::ZERO && rotation == Vec3::ZERO {
             continue;
         }
 
-        let original = shake.original_transform.unwrap();
-        let percent = shake.timer.fraction();
-        let damping_factor = 1.0 - percent * shake.damping;
-
-        // Time-based phase offset
-        let elapsed = shake.timer.elapsed_secs() + shake.seed;
-
-        // Translation offsets (sine waves)
-        let trans_offset = Vec3::new(
-            (elapsed * shake.translation_frequency.x * std::f32::consts::TAU).sin()
-                * shake.translation_intensity.x,
-            (elapsed * shake.translation_frequency.y * std::f32::consts::TAU).sin()
-                * shake.translation_intensity.y,
-            (elapsed * shake.translation_frequency.z * std::f32::consts::TAU).sin()
-                * shake.translation_intensity.z,
-        ) * damping_factor;
-
-        // Rotation offsets (sine waves)
-        let rot_offset = Quat::from_euler(
-            EulerRot::XYZ,
-            (elapsed * shake.rotation_frequency.x * std::f32::consts::TAU).sin()
-                * shake.rotation_intensity.x,
-            (elapsed * shake.rotation_frequency.y * std::f32::consts::TAU).sin()
-                * shake.rotation_intensity.y,
-            (elapsed * shake.rotation_frequency.z * std::f32::consts::TAU).sin()
-                * shake.rotation_intensity.z,
-        ) * damping_factor;
-
-        // Apply shake on top of original transform
-        tf.translation = original.translation + trans_offset;
-        tf.rotation = rot_offset * original.rotation;
+        // Apply shake on top of wherever the camera was put this frame
+        let rotation = Quat::from_euler(EulerRot::XYZ, rotation.x, rotation.y, rotation.z);
+        tf.translation += translation;
+        tf.rotation = rotation * tf.rotation;
+        state.applied = Some((translation, rotation));
     }
 }
@@ -0,0 +1,163 @@
+use std::f32::consts::FRAC_PI_4;
+
+use bevy::prelude::*;
+
+use crate::{
+    camera_time::{CameraTime, CameraTimeSource},
+    damping::Damping,
+};
+
+/// Over the shoulder camera for third person games.
+///
+/// The rig hangs off the target: a `shoulder` offset turned by the yaw, a
+/// `vertical_arm` raised along the aim's up, then `distance` straight back
+/// along the aim.  The camera always faces the aim direction, so the screen
+/// center ray is the aim ray, see [`ThirdPersonFollow::aim_ray`].
+#[derive(Component, Debug, Clone)]
+#[require(ThirdPersonState)]
+pub struct ThirdPersonFollow {
+    pub target: Entity,
+
+    /// Aim direction (in radians)
+    pub yaw: f32, // left/right
+    pub pitch: f32, // up/down
+    pub min_pitch: f32,
+    pub max_pitch: f32,
+
+    /// Offset from the target to the right shoulder, X is mirrored for the left one
+    pub shoulder: Vec3,
+    /// Height of the camera arm above the shoulder
+    pub vertical_arm: f32,
+    /// Distance from the end of the arm back to the camera
+    pub distance: f32,
+
+    /// Shoulder to sit behind, 0 is left and 1 is right
+    pub side: f32,
+    /// Damping of the swap between shoulders
+    pub side_damping: Damping,
+
+    /// Damping of the target's movement
+    pub damping: Damping,
+
+    /// Field of view (in radians) when not aiming
+    pub fov: f32,
+    /// Zoom into `aim_fov` while true
+    pub aiming: bool,
+    pub aim_fov: f32,
+    pub aim_damping: Damping,
+}
+
+/// Damped side and target position of a [`ThirdPersonFollow`].
+#[derive(Component, Debug, Clone, Default)]
+pub(crate) struct ThirdPersonState {
+    side: Option<f32>,
+    pivot: Option<Vec3>,
+}
+
+impl Default for ThirdPersonFollow {
+    fn default() -> Self {
+        Self {
+            target: Entity::PLACEHOLDER,
+            yaw: 0.,
+            pitch: 0.,
+            min_pitch: -1.4,
+            max_pitch: 1.4,
+            shoulder: Vec3::new(0.5, 1.5, 0.),
+            vertical_arm: 0.3,
+            distance: 3.,
+            side: 1.,
+            side_damping: Damping::new(0.2),
+            damping: Damping::new(0.1),
+            fov: FRAC_PI_4,
+            aiming: false,
+            aim_fov: FRAC_PI_4 * 0.5,
+            aim_damping: Damping::new(0.1),
+        }
+    }
+}
+
+impl ThirdPersonFollow {
+    /// Moves the camera to the other shoulder.
+    pub fn swap_side(&mut self) {
+        self.side = 1. - self.side;
+    }
+
+    /// Rotation of the aim, which is also the camera's rotation.
+    pub fn aim_rotation(&self) -> Quat {
+        Quat::from_euler(EulerRot::YXZ, self.yaw, self.pitch, 0.0)
+    }
+
+    /// Screen center ray of the camera at `vcam`, starting at the end of the arm
+    /// so it can't hit anything between the camera and the character.  Cast it
+    /// to find what the character is aiming at.
+    pub fn aim_ray(&self, vcam: &Transform) -> Ray3d {
+        let forward = vcam.forward();
+        Ray3d::new(vcam.translation + forward * self.distance, forward)
+    }
+}
+
+pub(crate) fn third_person_system(
+    time: Res<CameraTime>,
+    mut paramset: ParamSet<(
+        Query<(
+            Entity,
+            &mut ThirdPersonFollow,
+            &mut ThirdPersonState,
+            &mut Transform,
+            &mut Projection,
+            Option<&CameraTimeSource>,
+        )>,
+        TransformHelper,
+    )>,
+) {
+    let vcams = paramset.p0().iter().map(|(e, ..)| e).collect::<Vec<_>>();
+
+    for vcam in vcams {
+        let q = paramset.p0();
+        let Ok((_, rig, ..)) = q.get(vcam) else {
+            continue;
+        };
+        let target = rig.target;
+
+        let Ok(target_tf) = paramset.p1().compute_global_transform(target) else {
+            continue;
+        };
+        let target_pos = target_tf.translation();
+
+        let mut q = paramset.p0();
+        let Ok((_, mut rig, mut state, mut transform, mut projection, source)) = q.get_mut(vcam)
+        else {
+            continue;
+        };
+        let delta = time.delta_secs(source);
+        rig.pitch = rig.pitch.clamp(rig.min_pitch, rig.max_pitch);
+        let rotation = rig.aim_rotation();
+        let yaw = Quat::from_rotation_y(rig.yaw);
+
+        // Damp the target in aim space
+        let pivot = state.pivot.unwrap_or(target_pos);
+        let pivot = rig.damping.step_translation(pivot, target_pos, yaw, delta);
+        state.pivot = Some(pivot);
+
+        // Blend between shoulders
+        let side = state.side.unwrap_or(rig.side);
+        let remaining = rig.side - side;
+        let side = side + rig.side_damping.step_scalar(remaining, delta);
+        state.side = Some(side);
+
+        let shoulder = Vec3::new(
+            rig.shoulder.x * (side * 2. - 1.),
+            rig.shoulder.y,
+            rig.shoulder.z,
+        );
+        let hand = pivot + yaw * shoulder + rotation * Vec3::Y * rig.vertical_arm;
+        transform.translation = hand + rotation * Vec3::Z * rig.distance;
+        transform.rotation = rotation;
+
+        // Aim zoom
+        if let Projection::Perspective(p) = &mut *projection {
+            let fov = if rig.aiming { rig.aim_fov } else { rig.fov };
+            p.fov += rig.aim_damping.step_scalar(fov - p.fov, delta);
+        }
+    }
+}
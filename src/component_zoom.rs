@@ -1,132 +1,213 @@
+use std::f32::consts::{FRAC_PI_2, PI};
+
 use bevy::prelude::*;
 
-use crate::{world_to_ndc, DeadZone};
+use crate::{
+    camera_state::ortho_half_size,
+    camera_time::{CameraTime, CameraTimeSource},
+    damping::Damping,
+    prelude::{Director, VirtualCamera},
+    target_group::TargetGroup,
+    DeadZone,
+};
+
+/// Widest angle the field of view solver aims a ray at, to stay clear of tan() blowing up.
+const MAX_RAY_ANGLE: f32 = FRAC_PI_2 - 0.01;
+
+/// Narrowest field of view the solver zooms to, a lone point target would ask for 0.
+const MIN_FOV: f32 = 0.01;
+
+/// How a perspective [`GroupZoom`] camera frames its group.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GroupZoomMode {
+    /// Move the camera along its forward axis
+    #[default]
+    Dolly,
+    /// Keep the camera in place and change its field of view
+    Fov,
+}
 
-#[derive(Component, Debug, Clone, Default)]
+/// Zooms so the bounds of every group member fit inside `screen_rect`.
+///
+/// The camera's rotation and sideways position are left alone, pair it with
+/// a follow or look-at component to keep the group centered.
+#[derive(Component, Debug, Clone)]
 pub struct GroupZoom {
-    /// Entities to keep framed
-    pub targets: Vec<Entity>,
+    /// Entity with the [`TargetGroup`] to keep framed
+    pub group: Entity,
 
-    /// DeadZone
-    pub dead_zone: DeadZone,
+    /// Screen rectangle (NDC) the group is fitted into
+    pub screen_rect: DeadZone,
+
+    pub mode: GroupZoomMode,
 
     /// Optional smoothing factor (0 = instant)
-    pub damping: f32,
+    pub damping: Damping,
 
     /// Minimum and maximum allowed zoom level.
-    /// For perspective: distance limits.
+    /// For perspective dolly: distance to the group center.
+    /// For perspective FOV: field of view in radians.
     /// For orthographic: projection scale limits.
     pub min_scale: f32,
     pub max_scale: Option<f32>,
 }
 
+impl Default for GroupZoom {
+    fn default() -> Self {
+        Self {
+            group: Entity::PLACEHOLDER,
+            screen_rect: DeadZone {
+                xmin: -0.8,
+                xmax: 0.8,
+                ymin: -0.8,
+                ymax: 0.8,
+            },
+            mode: GroupZoomMode::default(),
+            damping: Damping::default(),
+            min_scale: 0.,
+            max_scale: None,
+        }
+    }
+}
+
 pub(crate) fn group_zoom_system(
-    mut paramset: ParamSet<(
-        Query<(Entity, &GroupZoom, &mut Transform, &mut Projection)>,
-        TransformHelper,
+    mut vcams: Query<(
+        &mut GroupZoom,
+        &VirtualCamera,
+        &mut Transform,
+        &mut Projection,
+        Option<&CameraTimeSource>,
     )>,
-    time: Res<Time<Real>>,
+    groups: Query<&TargetGroup>,
+    directors: Query<&Director>,
+    cameras: Query<&Camera>,
+    time: Res<CameraTime>,
 ) {
-    let delta = time.delta_secs();
-
-    let vcams = paramset.p0().iter().map(|(e, ..)| e).collect::<Vec<_>>();
-
-    for vcam in vcams {
-        let q = paramset.p0();
-        let Ok((_, zoom, _, _)) = q.get(vcam) else {
+    for (mut zoom, vcam, mut transform, mut projection, source) in vcams.iter_mut() {
+        let Some(bounds) = groups.get(zoom.group).ok().and_then(TargetGroup::bounds) else {
             continue;
         };
-        let valid_targets = zoom.targets.clone();
-        let count = valid_targets.len();
-        if count == 0 {
-            continue;
-        }
-
-        // Reference point (average position)
-        let mut ref_point = Vec3::ZERO;
-        let helper = paramset.p1();
-        let mut positions = vec![];
-        for target in valid_targets {
-            let Ok(global) = helper.compute_global_transform(target) else {
-                continue;
-            };
-            positions.push(global.translation());
-            ref_point += global.translation();
-        }
-        ref_point /= count as f32;
-
-        // Camera forward vector (world-space)
-        let mut q = paramset.p0();
-        let Ok((_, zoom, mut transform, mut projection)) = q.get_mut(vcam) else {
-            continue;
+        let delta = time.delta_secs(source);
+        let rect = zoom.screen_rect;
+        let max_scale = zoom.max_scale.unwrap_or(f32::INFINITY);
+
+        // Member spheres in camera space, with depth along forward as Z
+        let to_local = |world: Vec3| {
+            let local = transform.rotation.inverse() * (world - transform.translation);
+            Vec3::new(local.x, local.y, -local.z)
         };
-        let forward = transform.forward();
-
-        // Check deadzone for transverse axes (optional)
-        let mut breach = false;
-        for position in positions.iter() {
-            let ndc = world_to_ndc(*position, &transform, &*projection);
-            if ndc.x < zoom.dead_zone.xmin
-                || ndc.x > zoom.dead_zone.xmax
-                || ndc.y < zoom.dead_zone.ymin
-                || ndc.y > zoom.dead_zone.ymax
-            {
-                breach = true;
-                break;
-            }
-        }
+        let spheres = bounds
+            .spheres
+            .iter()
+            .map(|(center, radius)| (to_local(*center), *radius))
+            .collect::<Vec<_>>();
+        let center_depth = to_local(bounds.center).z;
 
         match &mut *projection {
-            Projection::Perspective(_) => {
-                // Compute distance along forward axis to reference point
-                let to_ref = ref_point - transform.translation;
-                let current_dist = to_ref.dot(forward.into());
-
-                // Prefer min_scale if group fits inside deadzone
-                let desired = if !breach {
-                    zoom.min_scale
-                } else {
-                    current_dist * 2.
-                };
-
-                // Clamp distance along forward
-                let desired_dist =
-                    (desired).clamp(zoom.min_scale, zoom.max_scale.unwrap_or(f32::INFINITY));
-
-                // Apply damping (scalar along forward)
-                let t = if zoom.damping > 0. {
-                    1.0 - (-delta / zoom.damping).exp()
-                } else {
-                    1.
-                };
-                let move_vec = forward * (current_dist - desired_dist) * t;
+            Projection::Perspective(p) if zoom.mode == GroupZoomMode::Dolly => {
+                let half_height = (p.fov * 0.5).tan();
+                let tan = Vec2::new(half_height * p.aspect_ratio, half_height);
+
+                // Furthest the camera can move forward with every member still inside the rect
+                let advance = spheres
+                    .iter()
+                    .map(|(local, radius)| local.z - dolly_depth(*local, *radius, tan, rect))
+                    .fold(f32::INFINITY, f32::min);
+
+                let desired_dist = (center_depth - advance).clamp(zoom.min_scale, max_scale);
 
                 // Move camera forward/back only
-                transform.translation += move_vec;
+                let forward = transform.forward();
+                transform.translation +=
+                    forward * zoom.damping.step_scalar(center_depth - desired_dist, delta);
             }
 
-            Projection::Orthographic(o) => {
-                // Adjust scale based on max forward offset
-                let mut max_offset: f32 = 0.0;
-                for position in positions {
-                    let offset = (position - ref_point).dot(forward.into()).abs();
-                    max_offset = max_offset.max(offset);
-                }
+            Projection::Perspective(p) => {
+                let tan = spheres
+                    .iter()
+                    .map(|(local, radius)| fov_tan(*local, *radius, rect, p.aspect_ratio))
+                    .fold(0., f32::max);
+
+                let desired_fov = (2.0 * tan.atan())
+                    .clamp(zoom.min_scale, max_scale)
+                    .clamp(MIN_FOV, PI - MIN_FOV);
+                p.fov += zoom.damping.step_scalar(desired_fov - p.fov, delta);
+            }
 
-                let mut desired_scale = max_offset.max(zoom.min_scale);
-                if let Some(max_scale) = zoom.max_scale {
-                    desired_scale = desired_scale.min(max_scale);
+            Projection::Orthographic(o) => {
+                // Half extents shown at a scale of 1
+                let viewport = directors
+                    .get(vcam.director)
+                    .ok()
+                    .and_then(|director| cameras.get(director.camera_entity).ok())
+                    .and_then(|camera| camera.logical_viewport_size());
+                let half_size = ortho_half_size(o, viewport);
+
+                let desired_scale = spheres
+                    .iter()
+                    .map(|(local, radius)| ortho_scale(local.xy(), *radius, half_size, rect))
+                    .fold(0., f32::max)
+                    .clamp(zoom.min_scale, max_scale);
+                if desired_scale <= 0. {
+                    continue;
                 }
 
-                let t = if zoom.damping > 0. {
-                    1.0 - (-delta / zoom.damping).exp()
-                } else {
-                    1.0
-                };
-                o.scale += (desired_scale - o.scale) * t;
+                o.scale += zoom.damping.step_scalar(desired_scale - o.scale, delta);
             }
 
             Projection::Custom(_) => {}
         }
     }
 }
+
+/// Screen edges as (camera space position along the edge's axis, NDC
+/// position of the edge, per axis `size`).
+fn edges(local: Vec2, rect: DeadZone, size: Vec2) -> [(f32, f32, f32); 4] {
+    [
+        (local.x, rect.xmin, size.x),
+        (local.x, rect.xmax, size.x),
+        (local.y, rect.ymin, size.y),
+        (local.y, rect.ymax, size.y),
+    ]
+}
+
+/// Smallest depth at which a sphere stays on the inner side of every edge plane.
+fn dolly_depth(local: Vec3, radius: f32, tan: Vec2, rect: DeadZone) -> f32 {
+    edges(local.xy(), rect, tan)
+        .into_iter()
+        .map(|(lateral, edge, tan)| (lateral, edge * tan))
+        .filter(|(_, slope)| slope.abs() > f32::EPSILON)
+        .map(|(lateral, slope)| {
+            (slope.signum() * lateral + radius * (1.0 + slope * slope).sqrt()) / slope.abs()
+        })
+        .fold(radius, f32::max)
+}
+
+/// Smallest tan(fov / 2) that keeps a sphere inside the rect.
+fn fov_tan(local: Vec3, radius: f32, rect: DeadZone, aspect_ratio: f32) -> f32 {
+    let distance = local.length();
+    let spread = if distance > radius {
+        (radius / distance).asin()
+    } else {
+        FRAC_PI_2
+    };
+
+    edges(local.xy(), rect, Vec2::new(aspect_ratio, 1.))
+        .into_iter()
+        .filter(|(_, edge, _)| edge.abs() > f32::EPSILON)
+        .map(|(lateral, edge, aspect_ratio)| {
+            let angle = (lateral.atan2(local.z) + edge.signum() * spread)
+                .clamp(-MAX_RAY_ANGLE, MAX_RAY_ANGLE);
+            angle.tan() / (edge * aspect_ratio)
+        })
+        .fold(0., f32::max)
+}
+
+/// Smallest orthographic scale that keeps a sphere inside the rect.
+fn ortho_scale(local: Vec2, radius: f32, half_size: Vec2, rect: DeadZone) -> f32 {
+    edges(local, rect, half_size)
+        .into_iter()
+        .filter(|(_, edge, _)| edge.abs() > f32::EPSILON)
+        .map(|(lateral, edge, half)| (lateral + edge.signum() * radius) / (edge * half))
+        .fold(0., f32::max)
+}
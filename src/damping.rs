@@ -0,0 +1,121 @@
+use bevy::prelude::*;
+
+/// How a camera catches up with where it wants to be.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DampingModel {
+    /// Covers ~63% of the remaining distance every `time` seconds.
+    #[default]
+    Exponential,
+    /// Spring with velocity state that settles in roughly `time` seconds
+    /// without overshooting.
+    CriticallyDamped,
+}
+
+/// Damping shared by the body and aim components.
+///
+/// Times are per camera local axis (X right, Y up, Z back).  For rotations the
+/// axes are pitch, yaw and roll.  A time of 0 snaps that axis.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Damping {
+    pub time: Vec3,
+    pub model: DampingModel,
+    /// Speed limit in units (or radians) per second
+    pub max_speed: Option<f32>,
+    pub(crate) velocity: Vec3,
+    // Rotation `velocity` is expressed in, for `step_translation`
+    pub(crate) frame: Option<Quat>,
+}
+
+impl Damping {
+    /// The same exponential damping on every axis.
+    pub fn new(time: f32) -> Self {
+        Self::per_axis(Vec3::splat(time))
+    }
+
+    pub fn per_axis(time: Vec3) -> Self {
+        Self { time, ..default() }
+    }
+
+    pub fn critically_damped(mut self) -> Self {
+        self.model = DampingModel::CriticallyDamped;
+        self
+    }
+
+    pub fn with_max_speed(mut self, max_speed: f32) -> Self {
+        self.max_speed = Some(max_speed);
+        self
+    }
+
+    /// Movement to apply this frame given the remaining `offset` to the goal.
+    pub(crate) fn step(&mut self, offset: Vec3, delta: f32) -> Vec3 {
+        let mut movement = Vec3::ZERO;
+        for axis in 0..3 {
+            let time = self.time[axis];
+            if time <= 0. {
+                movement[axis] = offset[axis];
+                self.velocity[axis] = 0.;
+                continue;
+            }
+            if delta <= 0. {
+                continue;
+            }
+
+            movement[axis] = match self.model {
+                DampingModel::Exponential => offset[axis] * (1.0 - (-delta / time).exp()),
+                DampingModel::CriticallyDamped => {
+                    // Closed form critically damped spring step
+                    let omega = 2.0 / time;
+                    let x = omega * delta;
+                    let decay = 1.0 / (1.0 + x + 0.48 * x * x + 0.235 * x * x * x);
+                    let error = -offset[axis];
+                    let temp = (self.velocity[axis] + omega * error) * delta;
+                    self.velocity[axis] = (self.velocity[axis] - omega * temp) * decay;
+                    (error + temp) * decay - error
+                }
+            };
+        }
+
+        if let Some(max_speed) = self.max_speed
+            && delta > 0.
+        {
+            movement = movement.clamp_length_max(max_speed * delta);
+            self.velocity = self.velocity.clamp_length_max(max_speed);
+        }
+        movement
+    }
+
+    /// Like [`Damping::step`] for a single value, damped on the Z (forward) axis.
+    pub(crate) fn step_scalar(&mut self, offset: f32, delta: f32) -> f32 {
+        self.step(Vec3::Z * offset, delta).z
+    }
+
+    /// Damps a world translation along the axes of `rotation`.
+    pub(crate) fn step_translation(
+        &mut self,
+        current: Vec3,
+        target: Vec3,
+        rotation: Quat,
+        delta: f32,
+    ) -> Vec3 {
+        // Carry the spring's velocity over into this frame's axes
+        if let Some(frame) = self.frame.replace(rotation) {
+            self.velocity = rotation.inverse() * (frame * self.velocity);
+        }
+
+        let local = rotation.inverse() * (target - current);
+        current + rotation * self.step(local, delta)
+    }
+
+    /// Damps a rotation per local axis: pitch on X, yaw on Y, roll on Z.
+    pub(crate) fn step_rotation(&mut self, current: Quat, target: Quat, delta: f32) -> Quat {
+        let (yaw, pitch, roll) = (current.inverse() * target).to_euler(EulerRot::YXZ);
+        let movement = self.step(Vec3::new(pitch, yaw, roll), delta);
+        (current * Quat::from_euler(EulerRot::YXZ, movement.y, movement.x, movement.z)).normalize()
+    }
+}
+
+impl From<f32> for Damping {
+    fn from(time: f32) -> Self {
+        Self::new(time)
+    }
+}
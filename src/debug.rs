@@ -1,12 +1,18 @@
-use crate::prelude::VirtualCamera;
+use crate::prelude::{DollyCart, DollyTrack, VirtualCamera};
 use bevy::prelude::*;
 
 #[derive(Component)]
 pub struct FrustumGizmo;
 
+/// Draws the [`DollyTrack`] on the same entity and the carts riding it.
+#[derive(Component)]
+pub struct TrackGizmo;
+
 pub(crate) fn draw_gizmos(
     mut gizmos: Gizmos,
     query: Query<(&GlobalTransform, &Projection), (With<VirtualCamera>, With<FrustumGizmo>)>,
+    tracks: Query<(Entity, &DollyTrack, &GlobalTransform), With<TrackGizmo>>,
+    carts: Query<(&DollyCart, &GlobalTransform)>,
 ) {
     for (transform, projection) in &query {
         if let Projection::Perspective(p) = projection {
@@ -16,6 +22,29 @@ pub(crate) fn draw_gizmos(
             draw_orthographic_frustum(&mut gizmos, transform, o);
         }
     }
+
+    for (entity, track, transform) in &tracks {
+        draw_track(&mut gizmos, track, transform);
+        for (_, cart_transform) in carts.iter().filter(|(cart, _)| cart.track == entity) {
+            gizmos.sphere(cart_transform.translation(), 0.15, LinearRgba::RED);
+        }
+    }
+}
+
+fn draw_track(gizmos: &mut Gizmos, track: &DollyTrack, transform: &GlobalTransform) {
+    gizmos.linestrip(
+        track
+            .samples()
+            .iter()
+            .map(|sample| transform.transform_point(*sample)),
+        Color::srgb(1.0, 0.8, 0.0),
+    );
+
+    // Mark where the curve segments join
+    for i in 0..=track.curve().segments().len() {
+        let point = transform.transform_point(track.curve().position(i as f32));
+        gizmos.sphere(point, 0.05, Color::WHITE);
+    }
 }
 
 fn draw_perspective_frustum(
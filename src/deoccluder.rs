@@ -0,0 +1,227 @@
+use std::marker::PhantomData;
+
+use bevy::{
+    camera::primitives::Aabb,
+    ecs::system::{ReadOnlySystemParam, StaticSystemParam, SystemParamItem},
+    prelude::*,
+};
+
+use crate::{
+    camera_time::{CameraTime, CameraTimeSource},
+    component_orbit::{orbit_camera_system, OrbitArm},
+    component_shake::add_shake,
+    damping::Damping,
+    VirtualCameraSystems,
+};
+
+/// Sphere casts used by [`Deoccluder`].  Implement it on top of your physics
+/// engine's spatial queries, or use [`MeshAabbBackend`].
+pub trait DeocclusionBackend: Send + Sync + 'static {
+    /// Read only access the cast needs, e.g. a spatial query
+    type Param: ReadOnlySystemParam;
+
+    /// Distance along `direction` at which a sphere of `radius` moving from
+    /// `origin` first touches something, if it does within `max_distance`.
+    /// Entities in `ignore` must not be hit.
+    fn sphere_cast(
+        param: &SystemParamItem<Self::Param>,
+        origin: Vec3,
+        direction: Dir3,
+        radius: f32,
+        max_distance: f32,
+        ignore: &[Entity],
+    ) -> Option<f32>;
+}
+
+/// Pulls an [`OrbitArm`] camera in front of whatever comes between it and its
+/// target.  Needs a [`DeocclusionPlugin`] for the chosen backend.
+#[derive(Component, Debug, Clone)]
+#[require(DeoccluderState)]
+pub struct Deoccluder {
+    /// Radius of the cast, keep it a bit larger than the camera's near plane
+    pub radius: f32,
+    /// Closest the camera gets pulled to the target
+    pub min_distance: f32,
+    /// Damping while pulling in towards the target
+    pub damping_in: Damping,
+    /// Damping while easing back out once the view clears
+    pub damping_out: Damping,
+    /// Entities the cast goes through, besides the target and the camera
+    pub ignore: Vec<Entity>,
+}
+
+/// Where a [`Deoccluder`] left the camera.
+#[derive(Component, Debug, Clone, Default)]
+pub(crate) struct DeoccluderState {
+    distance: Option<f32>,
+    correction: Vec3,
+}
+
+impl Default for Deoccluder {
+    fn default() -> Self {
+        Self {
+            radius: 0.2,
+            min_distance: 0.5,
+            damping_in: Damping::new(0.),
+            damping_out: Damping::new(0.5),
+            ignore: Vec::new(),
+        }
+    }
+}
+
+/// Adds deocclusion for [`OrbitArm`] cameras using the backend `B`.
+pub struct DeocclusionPlugin<B: DeocclusionBackend>(PhantomData<B>);
+
+impl<B: DeocclusionBackend> Default for DeocclusionPlugin<B> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<B: DeocclusionBackend> Plugin for DeocclusionPlugin<B> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            (
+                restore_deoccluded_system.before(VirtualCameraSystems),
+                deocclude_system::<B>
+                    .after(orbit_camera_system)
+                    .before(add_shake),
+            ),
+        );
+    }
+}
+
+/// Casts against the world space bounding box of every 3D mesh.  Boxes the
+/// cast starts inside are skipped, so the target's own meshes never occlude.
+pub struct MeshAabbBackend;
+
+impl DeocclusionBackend for MeshAabbBackend {
+    type Param =
+        Query<'static, 'static, (Entity, &'static GlobalTransform, &'static Aabb), With<Mesh3d>>;
+
+    fn sphere_cast(
+        meshes: &SystemParamItem<Self::Param>,
+        origin: Vec3,
+        direction: Dir3,
+        radius: f32,
+        max_distance: f32,
+        ignore: &[Entity],
+    ) -> Option<f32> {
+        let segment = direction * max_distance;
+
+        meshes
+            .iter()
+            .filter(|(entity, ..)| !ignore.contains(entity))
+            .filter_map(|(_, global, aabb)| {
+                // Cast in the box's local space, growing it by the sphere radius
+                let (scale, _, _) = global.to_scale_rotation_translation();
+                let inverse = global.affine().inverse();
+                let center = Vec3::from(aabb.center);
+                let half_extents = Vec3::from(aabb.half_extents);
+                let local_origin = inverse.transform_point3(origin) - center;
+                let local_segment = inverse.transform_vector3(segment);
+
+                if local_origin.abs().cmple(half_extents).all() {
+                    return None;
+                }
+
+                let grown = half_extents + radius / scale.abs().max(Vec3::splat(f32::EPSILON));
+                cast_box(local_origin, local_segment, grown)
+            })
+            .reduce(f32::min)
+            .map(|fraction| fraction * max_distance)
+    }
+}
+
+/// Fraction of `segment` at which it enters a box centered on the origin.
+fn cast_box(origin: Vec3, segment: Vec3, half_extents: Vec3) -> Option<f32> {
+    let inverse = segment.recip();
+    let t1 = (-half_extents - origin) * inverse;
+    let t2 = (half_extents - origin) * inverse;
+
+    let enter = t1.min(t2).max_element();
+    let exit = t1.max(t2).min_element();
+    if enter > exit || exit < 0. || enter > 1. {
+        return None;
+    }
+    Some(enter.max(0.))
+}
+
+/// Takes last frame's correction back out, so the body components damp from
+/// where they left the camera.
+fn restore_deoccluded_system(mut vcams: Query<(&mut DeoccluderState, &mut Transform)>) {
+    for (mut state, mut transform) in vcams.iter_mut() {
+        transform.translation -= std::mem::take(&mut state.correction);
+    }
+}
+
+fn deocclude_system<B: DeocclusionBackend>(
+    mut paramset: ParamSet<(
+        Query<(
+            Entity,
+            &OrbitArm,
+            &mut Deoccluder,
+            &mut DeoccluderState,
+            &mut Transform,
+            Option<&CameraTimeSource>,
+        )>,
+        TransformHelper,
+    )>,
+    backend: StaticSystemParam<B::Param>,
+    time: Res<CameraTime>,
+) {
+    let vcams = paramset.p0().iter().map(|(e, ..)| e).collect::<Vec<_>>();
+
+    for vcam in vcams {
+        let q = paramset.p0();
+        let Ok((_, orbit, ..)) = q.get(vcam) else {
+            continue;
+        };
+        let target = orbit.target;
+
+        let Ok(target_tf) = paramset.p1().compute_global_transform(target) else {
+            continue;
+        };
+
+        let mut q = paramset.p0();
+        let Ok((_, orbit, mut deoccluder, mut state, mut transform, source)) = q.get_mut(vcam)
+        else {
+            continue;
+        };
+        let delta = time.delta_secs(source);
+
+        // Cast from the point the arm looks at towards the camera
+        let pivot = target_tf.translation() + transform.rotation * orbit.offset;
+        let Ok((direction, desired)) = Dir3::new_and_length(transform.translation - pivot) else {
+            continue;
+        };
+
+        let mut ignore = vec![vcam, target];
+        ignore.extend_from_slice(&deoccluder.ignore);
+        let clear = B::sphere_cast(
+            &backend,
+            pivot,
+            direction,
+            deoccluder.radius,
+            desired,
+            &ignore,
+        )
+        .map_or(desired, |hit| hit.max(deoccluder.min_distance))
+        .min(desired);
+
+        // Pull in and ease out at different rates
+        let current = state.distance.unwrap_or(desired).min(desired);
+        let step = if clear < current {
+            deoccluder.damping_in.step_scalar(clear - current, delta)
+        } else {
+            deoccluder.damping_out.step_scalar(clear - current, delta)
+        };
+        let distance = current + step;
+        state.distance = Some(distance);
+
+        let correction = direction * (distance - desired);
+        state.correction = correction;
+        transform.translation += correction;
+    }
+}
@@ -1,12 +1,34 @@
-use bevy::prelude::*;
+use bevy::{platform::collections::HashMap, prelude::*};
 
-use crate::{blend::CameraBlendState, prelude::CameraState, virtual_camera::VirtualCamera};
+use crate::{
+    blend::{
+        BlendSource, BlendStackCollapse, CameraBlendMatrix, CameraBlendProgress, CameraBlendState,
+    },
+    prelude::CameraState,
+    virtual_camera::VirtualCamera,
+};
 
 #[derive(Component, Clone)]
 pub struct Director {
     pub active: Option<Entity>,                 // current virtual camera
+    pub blend_matrix: CameraBlendMatrix,        // per camera pair blend overrides
+    pub max_blend_depth: usize,                 // interrupted blends kept evaluating
+    pub blend_collapse: BlendStackCollapse,     // what to do with blends past max depth
     pub(crate) blend: Option<CameraBlendState>, // current blend (if between two)
     pub(crate) camera_entity: Entity,
+    pub(crate) overrides: Vec<Entity>, // cameras pushed by gameplay, last is live
+    pub(crate) solo: Option<Entity>,   // camera forced live over everything else
+    pub(crate) activations: HashMap<Entity, Activation>, // tie breaking by recency
+    pub(crate) next_activation: u64,
+    pub(crate) dirty: bool, // live camera needs to be re-elected
+    pub(crate) lost: bool,  // live camera was removed or disabled
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Activation {
+    priority: i32,
+    enabled: bool,
+    order: u64,
 }
 
 impl Director {
@@ -14,11 +36,199 @@ impl Director {
         Self {
             camera_entity,
             active: None,
+            blend_matrix: CameraBlendMatrix::default(),
+            max_blend_depth: 4,
+            blend_collapse: BlendStackCollapse::default(),
             blend: None,
+            overrides: Vec::new(),
+            solo: None,
+            activations: HashMap::default(),
+            next_activation: 0,
+            dirty: true,
+            lost: false,
+        }
+    }
+
+    pub fn with_blend_matrix(mut self, blend_matrix: CameraBlendMatrix) -> Self {
+        self.blend_matrix = blend_matrix;
+        self
+    }
+
+    /// Limits how many interrupted blends are stacked.  A depth of 1 blends
+    /// from a snapshot of the real camera whenever a blend is interrupted.
+    pub fn with_max_blend_depth(mut self, depth: usize, collapse: BlendStackCollapse) -> Self {
+        self.max_blend_depth = depth;
+        self.blend_collapse = collapse;
+        self
+    }
+
+    /// Makes `vcam` live until it is popped, regardless of priority.
+    pub fn push(&mut self, vcam: Entity) {
+        self.overrides.retain(|e| *e != vcam);
+        self.overrides.push(vcam);
+        self.dirty = true;
+    }
+
+    /// Removes the most recently pushed camera, returning to whatever was live before.
+    pub fn pop(&mut self) -> Option<Entity> {
+        self.dirty = true;
+        self.overrides.pop()
+    }
+
+    /// Makes `vcam` live over every pushed and prioritized camera until released.
+    pub fn solo(&mut self, vcam: Entity) {
+        self.solo = Some(vcam);
+        self.dirty = true;
+    }
+
+    /// Drops the solo camera and every pushed camera, handing control back to priorities.
+    pub fn release(&mut self) {
+        self.solo = None;
+        self.overrides.clear();
+        self.dirty = true;
+    }
+
+    /// The blend in progress, if any.
+    pub fn blend_progress(&self) -> Option<CameraBlendProgress> {
+        self.blend.as_ref().map(CameraBlendState::view)
+    }
+
+    /// Freezes the blend in progress until resumed.
+    pub fn pause_blend(&mut self) {
+        if let Some(blend) = &mut self.blend {
+            blend.paused = true;
         }
     }
+
+    pub fn resume_blend(&mut self) {
+        if let Some(blend) = &mut self.blend {
+            blend.paused = false;
+        }
+    }
+
+    /// Scales how fast the blend in progress advances.
+    pub fn set_blend_speed(&mut self, speed: f32) {
+        if let Some(blend) = &mut self.blend {
+            blend.speed = speed.max(0.);
+        }
+    }
+
+    /// Completes the blend in progress on the next update.
+    pub fn skip_blend(&mut self) {
+        if let Some(blend) = &mut self.blend {
+            blend.t = blend.definition.duration.as_secs_f32();
+            blend.paused = false;
+        }
+    }
+
+    /// Drops the blend in progress and cuts back to the camera that was live
+    /// before it.  That camera stays live until the next election.
+    ///
+    /// If that camera was removed or disabled since, a new live camera is
+    /// elected instead and `None` is returned.
+    pub fn cancel_blend(&mut self) -> Option<Entity> {
+        let origin = self.blend.take()?.origin?;
+        if !self.activations.get(&origin).is_some_and(|a| a.enabled) {
+            self.dirty = true;
+            return None;
+        }
+        self.active = Some(origin);
+        Some(origin)
+    }
+
+    /// Drops everything known about a removed camera.  Returns true if it was live.
+    fn forget(&mut self, vcam: Entity) -> bool {
+        let known = self.active == Some(vcam)
+            || self.solo == Some(vcam)
+            || self.overrides.contains(&vcam)
+            || self.activations.contains_key(&vcam);
+        if !known {
+            return false;
+        }
+
+        self.activations.remove(&vcam);
+        self.overrides.retain(|e| *e != vcam);
+        if self.solo == Some(vcam) {
+            self.solo = None;
+        }
+        self.dirty = true;
+
+        if self.active != Some(vcam) {
+            return false;
+        }
+
+        // Keep the dead camera as active so the next election blends away from it
+        self.lose_active();
+        true
+    }
+
+    /// The live camera can't be shown anymore, the next election blends in
+    /// with the new camera's own `blend_in`.
+    fn lose_active(&mut self) {
+        self.blend = None;
+        self.lost = true;
+        self.dirty = true;
+    }
+
+    /// Updates tie breaking order for a camera that was added or changed.
+    fn record_activation(&mut self, vcam_entity: Entity, vcam: &VirtualCamera) {
+        let previous = self.activations.get(&vcam_entity).copied();
+        let activated = previous.is_none_or(|previous| {
+            vcam.enabled && (!previous.enabled || previous.priority != vcam.priority)
+        });
+
+        let order = match previous {
+            Some(previous) if !activated => previous.order,
+            _ => {
+                self.next_activation += 1;
+                self.next_activation
+            }
+        };
+
+        self.activations.insert(
+            vcam_entity,
+            Activation {
+                priority: vcam.priority,
+                enabled: vcam.enabled,
+                order,
+            },
+        );
+        self.dirty = true;
+    }
+
+    /// Picks the live camera: solo, then the top pushed camera, then the highest
+    /// priority with ties going to the most recently activated camera.
+    fn elect(
+        &self,
+        director_entity: Entity,
+        vcams: &Query<(Entity, &VirtualCamera)>,
+    ) -> Option<Entity> {
+        let available = |entity: Entity| {
+            vcams
+                .get(entity)
+                .is_ok_and(|(_, vcam)| vcam.director == director_entity && vcam.enabled)
+        };
+
+        if let Some(solo) = self.solo.filter(|e| available(*e)) {
+            return Some(solo);
+        }
+        if let Some(pushed) = self.overrides.iter().rev().find(|e| available(**e)) {
+            return Some(*pushed);
+        }
+
+        vcams
+            .iter()
+            .filter(|(_, vcam)| vcam.director == director_entity && vcam.enabled)
+            .max_by_key(|(entity, vcam)| {
+                let order = self.activations.get(entity).map_or(0, |a| a.order);
+                (vcam.priority, order)
+            })
+            .map(|(entity, _)| entity)
+    }
 }
 
+/// Sent when a director starts moving to a new live camera.  Cuts send this
+/// and [`FinishedCameraBlend`] in the same frame.
 #[derive(Message, Copy, Clone, Debug)]
 pub struct StartedCameraBlend {
     pub from: Entity,
@@ -30,41 +240,94 @@ pub struct FinishedCameraBlend {
     pub to: Entity,
 }
 
+/// Sent when a director's live virtual camera is despawned, loses its
+/// [`VirtualCamera`] component or is disabled.
+#[derive(Message, Copy, Clone, Debug)]
+pub struct LostLiveCamera {
+    pub director: Entity,
+    pub vcam: Entity,
+}
+
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn update_active_camera(
     mut directors: Query<(Entity, &mut Director)>,
     vcams: Query<(Entity, &VirtualCamera)>,
-    updates: Query<Entity, Changed<VirtualCamera>>,
+    updates: Query<(Entity, &VirtualCamera), Changed<VirtualCamera>>,
+    mut removed: RemovedComponents<VirtualCamera>,
     mut message_writer: MessageWriter<StartedCameraBlend>,
+    mut finished_writer: MessageWriter<FinishedCameraBlend>,
+    mut lost_writer: MessageWriter<LostLiveCamera>,
     current_state: Query<(&Transform, &Projection)>,
 ) {
-    if updates.count() == 0 {
-        return;
+    for vcam_entity in removed.read() {
+        for (director_entity, mut director) in directors.iter_mut() {
+            if director.forget(vcam_entity) {
+                lost_writer.write(LostLiveCamera {
+                    director: director_entity,
+                    vcam: vcam_entity,
+                });
+            }
+        }
     }
 
-    for (director_entity, mut director) in directors.iter_mut() {
-        let mut max_priority = i32::MIN;
-        let mut active_cam = Entity::PLACEHOLDER;
-        for (vcam_entity, vcam) in vcams {
-            if vcam.director != director_entity {
-                continue;
-            }
-            if vcam.priority > max_priority {
-                max_priority = vcam.priority;
-                active_cam = vcam_entity;
+    for (vcam_entity, vcam) in updates.iter() {
+        if let Ok((director_entity, mut director)) = directors.get_mut(vcam.director) {
+            director.record_activation(vcam_entity, vcam);
+
+            // Disabling the live camera loses it just like removing it
+            if !vcam.enabled && director.active == Some(vcam_entity) {
+                director.lose_active();
+                lost_writer.write(LostLiveCamera {
+                    director: director_entity,
+                    vcam: vcam_entity,
+                });
             }
         }
+    }
+
+    for (director_entity, mut director) in directors.iter_mut() {
+        if !director.dirty {
+            continue;
+        }
+        director.dirty = false;
+        let lost = std::mem::take(&mut director.lost);
+
+        let Some(active_cam) = director.elect(director_entity, &vcams) else {
+            // Nothing is allowed to be live
+            director.active = None;
+            director.blend = None;
+            continue;
+        };
 
         match director.active {
             Some(current) if current == active_cam => {}
-            Some(_current) => {
+            Some(previous) => {
+                let (_, new_vcam) = vcams.get(active_cam).unwrap();
+
+                // A lost camera has no pair to look up, so blend in with the new camera's own blend
+                let definition = if !lost && vcams.contains(previous) {
+                    director
+                        .blend_matrix
+                        .find(Some(previous), active_cam)
+                        .unwrap_or(&new_vcam.blend_in)
+                } else {
+                    &new_vcam.blend_in
+                }
+                .clone();
+
                 // Start blending from current -> new.
-                if let Some(previous) = director.active {
-                    message_writer.write(StartedCameraBlend {
-                        from: previous,
-                        to: active_cam,
-                    });
+                message_writer.write(StartedCameraBlend {
+                    from: previous,
+                    to: active_cam,
+                });
+
+                // Cuts switch immediately, dropping any blend in progress
+                if definition.is_cut() {
+                    director.active = Some(active_cam);
+                    director.blend = None;
+                    finished_writer.write(FinishedCameraBlend { to: active_cam });
+                    continue;
                 }
-                let (_, new_vcam) = vcams.get(active_cam).unwrap();
 
                 // Blend from current camera position, not necessarily the from camera (e.g. blend interrupts)
                 let (current_transform, current_projection) = current_state
@@ -75,7 +338,22 @@ pub(crate) fn update_active_camera(
                     projection: current_projection.clone(),
                 };
 
-                director.blend = Some(new_vcam.blend_in.create(current_state, active_cam));
+                // Interrupted blends keep evaluating underneath the new one,
+                // live blends keep the outgoing camera moving.
+                let from = match director.blend.take() {
+                    Some(interrupted) if director.max_blend_depth > 1 => {
+                        BlendSource::Blend(Box::new(interrupted))
+                    }
+                    None if definition.live => BlendSource::Live {
+                        vcam: previous,
+                        last: current_state,
+                    },
+                    _ => BlendSource::Snapshot(current_state),
+                };
+
+                let mut blend = definition.create(from, Some(previous), active_cam);
+                blend.collapse(director.max_blend_depth, director.blend_collapse);
+                director.blend = Some(blend);
                 director.active = Some(active_cam);
             }
             None => {
@@ -0,0 +1,146 @@
+use bevy::prelude::*;
+
+use crate::{
+    camera_time::{CameraTime, CameraTimeSource},
+    component_shake::{CameraShake, Shake},
+};
+
+/// Every clock an impulse keeps time on, indexed by `CameraTimeSource as usize`.
+const CLOCKS: [CameraTimeSource; 4] = [
+    CameraTimeSource::Real,
+    CameraTimeSource::Virtual,
+    CameraTimeSource::Fixed,
+    CameraTimeSource::Custom,
+];
+
+/// Something in the world that shakes nearby cameras, e.g. an explosion or a
+/// giant's footsteps.  Fire it with [`GenerateImpulse`].
+#[derive(Component, Debug, Clone)]
+pub struct ImpulseSource {
+    /// Shake felt by a listener right at the source
+    pub shake: Shake,
+    /// Distance at which the shake has faded out completely
+    pub radius: f32,
+    /// Speed the shake travels at (units per second), 0 reaches every listener at once
+    pub propagation_speed: f32,
+}
+
+impl Default for ImpulseSource {
+    fn default() -> Self {
+        Self {
+            shake: Shake::default(),
+            radius: 20.,
+            propagation_speed: 0.,
+        }
+    }
+}
+
+/// Lets a camera feel [`ImpulseSource`]s.  Shakes fade linearly with distance
+/// and are added to the camera's [`CameraShake`].  Impulses travel on the
+/// camera's own clock.
+#[derive(Component, Debug, Clone, Copy)]
+#[require(CameraShake)]
+pub struct ImpulseListener {
+    /// Scales every shake received
+    pub gain: f32,
+}
+
+impl Default for ImpulseListener {
+    fn default() -> Self {
+        Self { gain: 1. }
+    }
+}
+
+/// Fires an [`ImpulseSource`] from where it is now.
+#[derive(Message, Copy, Clone, Debug)]
+pub struct GenerateImpulse {
+    pub source: Entity,
+    /// Scales the source's shake
+    pub strength: f32,
+}
+
+/// Impulses still travelling outwards.
+#[derive(Resource, Default)]
+pub(crate) struct ActiveImpulses(Vec<Impulse>);
+
+struct Impulse {
+    origin: Vec3,
+    source: ImpulseSource,
+    strength: f32,
+    // Time since firing on each clock, listeners see the front move on theirs
+    elapsed: [f32; CLOCKS.len()],
+    reached: Vec<Entity>,
+}
+
+pub(crate) fn impulse_system(
+    mut reader: MessageReader<GenerateImpulse>,
+    mut impulses: ResMut<ActiveImpulses>,
+    sources: Query<&ImpulseSource>,
+    helper: TransformHelper,
+    mut listeners: Query<(
+        Entity,
+        &ImpulseListener,
+        &GlobalTransform,
+        &mut CameraShake,
+        Option<&CameraTimeSource>,
+    )>,
+    time: Res<CameraTime>,
+) {
+    for GenerateImpulse { source, strength } in reader.read() {
+        let (Ok(impulse), Ok(origin)) = (
+            sources.get(*source),
+            helper.compute_global_transform(*source),
+        ) else {
+            continue;
+        };
+        impulses.0.push(Impulse {
+            origin: origin.translation(),
+            source: impulse.clone(),
+            strength: *strength,
+            elapsed: [0.; CLOCKS.len()],
+            reached: Vec::new(),
+        });
+    }
+
+    impulses.0.retain_mut(|impulse| {
+        // How far the wave front got on a clock
+        let elapsed = impulse.elapsed;
+        let front_on = |clock: CameraTimeSource| {
+            if impulse.source.propagation_speed > 0. {
+                elapsed[clock as usize] * impulse.source.propagation_speed
+            } else {
+                f32::INFINITY
+            }
+        };
+        for clock in CLOCKS {
+            impulse.elapsed[clock as usize] += time.delta_secs(Some(&clock));
+        }
+
+        // Done once the front passed the edge of the radius on every clock in use
+        let mut pending = front_on(time.source(None)) < impulse.source.radius;
+
+        for (entity, listener, global, mut shake, source) in listeners.iter_mut() {
+            let front = front_on(time.source(source));
+            pending |= front < impulse.source.radius;
+
+            let distance = global.translation().distance(impulse.origin);
+            if distance > front || impulse.reached.contains(&entity) {
+                continue;
+            }
+            impulse.reached.push(entity);
+
+            let falloff = 1. - distance / impulse.source.radius.max(f32::EPSILON);
+            if falloff <= 0. {
+                continue;
+            }
+
+            let scale = falloff * impulse.strength * listener.gain;
+            let mut received = impulse.source.shake.clone();
+            received.translation_intensity *= scale;
+            received.rotation_intensity *= scale;
+            shake.shakes.push(received);
+        }
+
+        pending
+    });
+}
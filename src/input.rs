@@ -0,0 +1,158 @@
+use bevy::{
+    input::mouse::{AccumulatedMouseMotion, AccumulatedMouseScroll, MouseScrollUnit},
+    prelude::*,
+};
+
+use crate::{
+    camera_time::{CameraTime, CameraTimeSource},
+    component_freelook::FreeLook,
+    component_orbit::OrbitArm,
+    component_third_person::ThirdPersonFollow,
+    director::Director,
+    virtual_camera::VirtualCamera,
+};
+
+/// Scroll distance treated as one wheel notch on touchpads and other pixel based devices.
+const SCROLL_PIXELS_PER_LINE: f32 = 100.;
+
+/// Drives the yaw and pitch of a [`FreeLook`], [`OrbitArm`] or
+/// [`ThirdPersonFollow`] on the same entity from the mouse and gamepad right
+/// stick.  Scrolling zooms an [`OrbitArm`] in and out.
+#[derive(Component, Debug, Clone)]
+pub struct CameraInput {
+    /// Radians per pixel of mouse motion
+    pub mouse_sensitivity: Vec2,
+    /// Radians per second at full stick deflection
+    pub gamepad_sensitivity: Vec2,
+    pub invert_x: bool,
+    pub invert_y: bool,
+    /// Exponent of the stick response, 1 is linear and larger values give
+    /// finer control near the center
+    pub acceleration: f32,
+    /// Stick deflection ignored around the center (0 to 1)
+    pub deadzone: f32,
+    /// Mouse button that has to be held to look around, `None` always reads the mouse
+    pub mouse_button: Option<MouseButton>,
+    /// Gamepad to read, `None` reads every connected gamepad
+    pub gamepad: Option<Entity>,
+    /// Change of orbit radius per scroll notch
+    pub zoom_sensitivity: f32,
+    pub min_radius: f32,
+    pub max_radius: f32,
+    /// Ignore input while the camera isn't live
+    pub only_when_live: bool,
+}
+
+impl Default for CameraInput {
+    fn default() -> Self {
+        Self {
+            mouse_sensitivity: Vec2::splat(0.003),
+            gamepad_sensitivity: Vec2::new(3., 2.),
+            invert_x: false,
+            invert_y: false,
+            acceleration: 2.,
+            deadzone: 0.1,
+            mouse_button: None,
+            gamepad: None,
+            zoom_sensitivity: 0.5,
+            min_radius: 1.,
+            max_radius: 20.,
+            only_when_live: true,
+        }
+    }
+}
+
+impl CameraInput {
+    /// Applies the deadzone and acceleration curve to a stick position.
+    fn shape_stick(&self, stick: Vec2) -> Vec2 {
+        let length = stick.length().min(1.);
+        if length <= self.deadzone {
+            return Vec2::ZERO;
+        }
+
+        let scaled = (length - self.deadzone) / (1. - self.deadzone);
+        stick.normalize() * scaled.powf(self.acceleration.max(f32::EPSILON))
+    }
+}
+
+pub(crate) fn camera_input_system(
+    mut vcams: Query<(
+        Entity,
+        &CameraInput,
+        &VirtualCamera,
+        Option<&mut FreeLook>,
+        Option<&mut OrbitArm>,
+        Option<&mut ThirdPersonFollow>,
+        Option<&CameraTimeSource>,
+    )>,
+    directors: Query<&Director>,
+    gamepads: Query<(Entity, &Gamepad)>,
+    mouse_motion: Option<Res<AccumulatedMouseMotion>>,
+    mouse_scroll: Option<Res<AccumulatedMouseScroll>>,
+    mouse_buttons: Option<Res<ButtonInput<MouseButton>>>,
+    time: Res<CameraTime>,
+) {
+    for (entity, input, vcam, free_look, orbit, third_person, source) in vcams.iter_mut() {
+        if input.only_when_live
+            && directors
+                .get(vcam.director)
+                .is_ok_and(|director| director.active != Some(entity))
+        {
+            continue;
+        }
+        let delta = time.delta_secs(source);
+
+        // Look input in screen directions, X right and Y up
+        let mut look = Vec2::ZERO;
+        let button_held = input.mouse_button.is_none_or(|button| {
+            mouse_buttons
+                .as_ref()
+                .is_some_and(|buttons| buttons.pressed(button))
+        });
+        if let Some(motion) = mouse_motion.as_ref().filter(|_| button_held) {
+            look += motion.delta * Vec2::new(1., -1.) * input.mouse_sensitivity;
+        }
+
+        let stick = gamepads
+            .iter()
+            .filter(|(gamepad, _)| input.gamepad.is_none_or(|e| e == *gamepad))
+            .map(|(_, gamepad)| input.shape_stick(gamepad.right_stick()))
+            .fold(Vec2::ZERO, |acc, stick| acc + stick)
+            .clamp_length_max(1.);
+        look += stick * input.gamepad_sensitivity * delta;
+
+        if input.invert_x {
+            look.x = -look.x;
+        }
+        if input.invert_y {
+            look.y = -look.y;
+        }
+
+        let zoom = mouse_scroll
+            .as_ref()
+            .map_or(0., |scroll| match scroll.unit {
+                MouseScrollUnit::Line => scroll.delta.y,
+                MouseScrollUnit::Pixel => scroll.delta.y / SCROLL_PIXELS_PER_LINE,
+            });
+
+        if let Some(mut free_look) = free_look {
+            free_look.yaw -= look.x;
+            free_look.pitch += look.y;
+        }
+
+        if let Some(mut third_person) = third_person {
+            third_person.yaw -= look.x;
+            third_person.pitch += look.y;
+        }
+
+        // The arm swings the camera around, so it turns the other way
+        if let Some(mut orbit) = orbit {
+            orbit.yaw += look.x;
+            orbit.pitch -= look.y;
+            if zoom != 0. {
+                orbit.radius = (orbit.radius - zoom * input.zoom_sensitivity)
+                    .clamp(input.min_radius, input.max_radius);
+            }
+        }
+    }
+}
@@ -1,31 +1,60 @@
 mod blend;
 mod camera_state;
+mod camera_time;
+mod component_2d;
 mod component_copy_rotation;
+mod component_dolly;
 mod component_follow;
 mod component_freelook;
 mod component_lookat;
 mod component_orbit;
 mod component_shake;
+mod component_third_person;
 mod component_zoom;
+mod damping;
 mod debug;
+mod deoccluder;
 mod director;
+mod impulse;
+mod input;
+mod look_ahead;
+mod noise;
+mod shake_profile;
+mod split_screen;
+mod target_group;
 mod virtual_camera;
 
 use bevy::prelude::*;
+use virtual_camera::VirtualCamera;
 
 pub mod prelude {
     pub use crate::{
-        blend::CameraBlendDefinition,
+        blend::{
+            BlendCamera, BlendMode, BlendSource, BlendStackCollapse, CameraBlendDefinition,
+            CameraBlendMatrix, CameraBlendMatrixEntry, CameraBlendProgress,
+        },
         camera_state::CameraState,
+        camera_time::{CameraClock, CameraTimeSource},
+        component_2d::{Follow2d, PixelSnap, ZoomToFit2d},
         component_copy_rotation::CopyRotation,
-        component_follow::{FollowGroup, FollowTarget},
+        component_dolly::{DollyCart, DollyCartMode, DollyCartState, DollyRotation, DollyTrack},
+        component_follow::{FollowFraming, FollowGroup, FollowTarget},
         component_freelook::FreeLook,
         component_lookat::{LookAtGroup, LookAtTarget},
         component_orbit::OrbitArm,
-        component_shake::{AddCameraShake, Shake},
-        component_zoom::GroupZoom,
-        debug::FrustumGizmo,
-        director::{Director, FinishedCameraBlend, StartedCameraBlend},
+        component_shake::{AddCameraShake, CameraShake, Shake, ShakeWaveform, Trauma},
+        component_third_person::ThirdPersonFollow,
+        component_zoom::{GroupZoom, GroupZoomMode},
+        damping::{Damping, DampingModel},
+        debug::{FrustumGizmo, TrackGizmo},
+        deoccluder::{Deoccluder, DeocclusionBackend, DeocclusionPlugin, MeshAabbBackend},
+        director::{Director, FinishedCameraBlend, LostLiveCamera, StartedCameraBlend},
+        impulse::{GenerateImpulse, ImpulseListener, ImpulseSource},
+        input::CameraInput,
+        look_ahead::LookAhead,
+        shake_profile::{EnvelopeCurve, ShakeEnvelope, ShakeProfile, ShakeProfileLoaderError},
+        split_screen::{split_screen_rects, SplitScreen},
+        target_group::{GroupBounds, TargetGroup, TargetGroupMember},
         virtual_camera::VirtualCamera,
         DeadZone, VirtualCameraPlugin,
     };
@@ -34,40 +63,63 @@ pub mod prelude {
 #[derive(SystemSet, Eq, PartialEq, Hash, Debug, Copy, Clone)]
 pub struct VirtualCameraSystems;
 
-pub struct VirtualCameraPlugin;
+#[derive(Default)]
+pub struct VirtualCameraPlugin {
+    /// Clock used by cameras without their own `CameraTimeSource`
+    pub time_source: camera_time::CameraTimeSource,
+}
 
 impl Plugin for VirtualCameraPlugin {
     fn build(&self, app: &mut App) {
-        app.add_message::<component_shake::AddCameraShake>()
+        app.insert_resource(camera_time::CameraTime::new(self.time_source))
+            .add_message::<component_shake::AddCameraShake>()
             .add_message::<director::StartedCameraBlend>()
             .add_message::<director::FinishedCameraBlend>()
+            .add_message::<director::LostLiveCamera>()
+            .add_message::<impulse::GenerateImpulse>()
+            .init_resource::<impulse::ActiveImpulses>()
             .add_systems(
                 Update,
                 (
+                    split_screen::split_screen_system,
                     virtual_camera::sync_aspect_ratios,
                     virtual_camera::on_window_resize,
-                ),
+                )
+                    .chain(),
             )
             .add_systems(
                 PostUpdate,
                 (
+                    camera_time::update_camera_time,
+                    // Before the director snapshots the real camera for a blend
+                    component_shake::remove_shake_system,
                     director::update_active_camera,
+                    input::camera_input_system,
+                    target_group::update_target_groups,
                     (
                         component_copy_rotation::copy_rotation_system,
                         component_follow::follow_target_system,
                         component_follow::follow_group_system,
+                        component_follow::follow_framing_system,
+                        component_2d::follow_2d_system,
+                        component_dolly::dolly_cart_system,
                         component_zoom::group_zoom_system,
+                        component_2d::zoom_to_fit_2d_system,
                         component_lookat::look_at_system,
                         component_lookat::look_at_group_system,
                         component_freelook::free_look_system,
                         component_orbit::orbit_camera_system,
+                        component_third_person::third_person_system,
                         component_shake::add_shake,
-                        component_shake::camera_shake_system,
+                        impulse::impulse_system,
+                        component_shake::camera_shake_system::<With<VirtualCamera>>,
                     )
                         .chain()
                         .in_set(VirtualCameraSystems),
                     blend::camera_blend_update_system,
                     virtual_camera::camera_apply_system,
+                    component_shake::camera_shake_system::<Without<VirtualCamera>>,
+                    component_2d::pixel_snap_system,
                 )
                     .chain(),
             )
@@ -76,6 +128,14 @@ impl Plugin for VirtualCameraPlugin {
                 debug::draw_gizmos.after(TransformSystems::Propagate),
             );
     }
+
+    // Every plugin is built by now, so this works whichever order they were added in
+    fn finish(&self, app: &mut App) {
+        if app.is_plugin_added::<AssetPlugin>() {
+            app.init_asset::<shake_profile::ShakeProfile>()
+                .register_asset_loader(shake_profile::ShakeProfileLoader);
+        }
+    }
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Default)]
@@ -93,6 +153,26 @@ impl DeadZone {
         ymin: 0.,
         ymax: 0.,
     };
+
+    /// The whole screen in NDC.
+    pub const FULL: DeadZone = DeadZone {
+        xmin: -1.,
+        xmax: 1.,
+        ymin: -1.,
+        ymax: 1.,
+    };
+
+    pub fn contains(&self, point: Vec2) -> bool {
+        point.x >= self.xmin && point.x <= self.xmax && point.y >= self.ymin && point.y <= self.ymax
+    }
+
+    /// Closest point to `point` inside the zone.
+    pub fn clamp(&self, point: Vec2) -> Vec2 {
+        Vec2::new(
+            point.x.clamp(self.xmin, self.xmax),
+            point.y.clamp(self.ymin, self.ymax),
+        )
+    }
 }
 
 pub fn world_to_ndc(world_pos: Vec3, camera_tf: &Transform, projection: &Projection) -> Vec2 {
@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+
+/// Leads a moving target by predicting where it will be from its recent
+/// velocity.
+///
+/// The velocity is estimated from the target's world position each frame and
+/// smoothed over `smoothing` seconds, so jittery movement doesn't shake the
+/// camera.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LookAhead {
+    /// How far into the future to predict, in seconds
+    pub time: f32,
+    /// Smoothing time of the velocity estimate, in seconds
+    pub smoothing: f32,
+    /// Ignore vertical motion, so jumping doesn't bob the camera
+    pub ignore_y: bool,
+    pub(crate) last_position: Option<Vec3>,
+    pub(crate) velocity: Vec3,
+}
+
+impl LookAhead {
+    pub fn new(time: f32, smoothing: f32) -> Self {
+        Self {
+            time,
+            smoothing,
+            ..default()
+        }
+    }
+
+    pub fn ignore_vertical(mut self) -> Self {
+        self.ignore_y = true;
+        self
+    }
+
+    /// Forgets the target's history, e.g. after it teleported.
+    pub fn reset(&mut self) {
+        self.last_position = None;
+        self.velocity = Vec3::ZERO;
+    }
+
+    /// Records the target at `position` and returns where it is predicted to be.
+    pub(crate) fn predict(&mut self, position: Vec3, delta: f32) -> Vec3 {
+        if delta > 0. {
+            if let Some(last) = self.last_position {
+                let velocity = (position - last) / delta;
+                let t = if self.smoothing > 0. {
+                    1.0 - (-delta / self.smoothing).exp()
+                } else {
+                    1.0
+                };
+                self.velocity = self.velocity.lerp(velocity, t);
+            }
+            self.last_position = Some(position);
+        }
+
+        let mut lead = self.velocity * self.time;
+        if self.ignore_y {
+            lead.y = 0.;
+        }
+        position + lead
+    }
+}
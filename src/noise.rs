@@ -0,0 +1,38 @@
+/// Pseudo random gradient in [-1, 1] for an integer lattice point.
+fn gradient(i: i32) -> f32 {
+    let mut h = (i as u32).wrapping_mul(0x9E37_79B9);
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85EB_CA6B);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xC2B2_AE35);
+    h ^= h >> 16;
+    (h as f32 / u32::MAX as f32) * 2.0 - 1.0
+}
+
+/// 1D Perlin noise, roughly in [-1, 1] and 0 at every integer.
+pub(crate) fn perlin(x: f32) -> f32 {
+    let i = x.floor();
+    let f = x - i;
+    let i = i as i32;
+
+    // Quintic fade keeps the curve smooth across lattice points
+    let u = f * f * f * (f * (f * 6.0 - 15.0) + 10.0);
+    let a = gradient(i) * f;
+    let b = gradient(i.wrapping_add(1)) * (f - 1.0);
+    (a + (b - a) * u) * 2.0
+}
+
+/// Sum of `octaves` layers of Perlin noise, normalized to roughly [-1, 1].
+pub(crate) fn fractal(x: f32, octaves: u32, lacunarity: f32, persistence: f32) -> f32 {
+    let mut sum = 0.;
+    let mut total = 0.;
+    let mut frequency = 1.;
+    let mut amplitude = 1.;
+    for _ in 0..octaves.max(1) {
+        sum += perlin(x * frequency) * amplitude;
+        total += amplitude;
+        frequency *= lacunarity;
+        amplitude *= persistence;
+    }
+    sum / total
+}
@@ -0,0 +1,188 @@
+use std::time::Duration;
+
+use bevy::{
+    asset::{io::Reader, AssetLoader, LoadContext},
+    prelude::*,
+};
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::component_shake::{Shake, ShakeWaveform};
+
+/// Easing of an envelope ramp.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+pub enum EnvelopeCurve {
+    #[default]
+    Linear,
+    /// Starts slow, ends fast
+    EaseIn,
+    /// Starts fast, ends slow
+    EaseOut,
+    /// Slow at both ends
+    Smooth,
+}
+
+impl EnvelopeCurve {
+    fn ease(&self, t: f32) -> f32 {
+        let t = t.clamp(0., 1.);
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => t * t,
+            Self::EaseOut => 1. - (1. - t) * (1. - t),
+            Self::Smooth => t * t * (3. - 2. * t),
+        }
+    }
+}
+
+/// Level over time, rising from 0 to 1 over `attack` seconds, holding for
+/// `sustain` seconds and falling back to 0 over `decay` seconds.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct ShakeEnvelope {
+    pub attack: f32,
+    pub sustain: f32,
+    pub decay: f32,
+    pub attack_curve: EnvelopeCurve,
+    pub decay_curve: EnvelopeCurve,
+}
+
+impl ShakeEnvelope {
+    pub fn duration(&self) -> f32 {
+        self.attack + self.sustain + self.decay
+    }
+
+    /// Level `elapsed` seconds in.
+    pub fn sample(&self, elapsed: f32) -> f32 {
+        if elapsed < self.attack {
+            return self.attack_curve.ease(elapsed / self.attack);
+        }
+        let elapsed = elapsed - self.attack;
+        if elapsed < self.sustain {
+            return 1.;
+        }
+        let elapsed = elapsed - self.sustain;
+        if elapsed < self.decay {
+            return 1. - self.decay_curve.ease(elapsed / self.decay);
+        }
+        0.
+    }
+}
+
+/// Shake preset loaded from a `.shake.ron` file, played with
+/// [`AddCameraShake::Profile`](crate::prelude::AddCameraShake::Profile).
+///
+/// Shakes in progress read the asset every frame, so edits show up right
+/// away with Bevy's `file_watcher` feature enabled.
+///
+/// ```ron
+/// (
+///     translation_intensity: (0.2, 0.3, 0.1),
+///     translation_frequency: (12.0, 10.0, 8.0),
+///     waveform: Noise(octaves: 3, lacunarity: 2.0, persistence: 0.5),
+///     intensity: (attack: 0.05, sustain: 0.1, decay: 0.8, decay_curve: EaseOut),
+/// )
+/// ```
+#[derive(Asset, TypePath, Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct ShakeProfile {
+    /// Maximum translation offset along each axis (world units)
+    pub translation_intensity: Vec3,
+    /// Maximum rotation offset along each axis (radians)
+    pub rotation_intensity: Vec3,
+    /// Frequency of translation shake (Hz)
+    pub translation_frequency: Vec3,
+    /// Frequency of rotation shake (Hz)
+    pub rotation_frequency: Vec3,
+    pub waveform: ShakeWaveform,
+    pub seed: f32,
+    /// Intensity over time, its duration is the length of the shake
+    pub intensity: ShakeEnvelope,
+    /// Frequency over time, `None` keeps it constant
+    pub frequency: Option<ShakeEnvelope>,
+}
+
+impl From<&ShakeProfile> for Shake {
+    fn from(profile: &ShakeProfile) -> Self {
+        Self {
+            timer: Timer::new(
+                Duration::from_secs_f32(profile.intensity.duration()),
+                TimerMode::Once,
+            ),
+            translation_intensity: profile.translation_intensity,
+            rotation_intensity: profile.rotation_intensity,
+            translation_frequency: profile.translation_frequency,
+            rotation_frequency: profile.rotation_frequency,
+            waveform: profile.waveform,
+            damping: 0.,
+            seed: profile.seed,
+        }
+    }
+}
+
+/// A [`ShakeProfile`] playing on a camera.
+#[derive(Debug, Clone)]
+pub(crate) struct ProfileShake {
+    pub(crate) profile: Handle<ShakeProfile>,
+    pub(crate) scale: f32,
+    pub(crate) elapsed: f32,
+    // Time scaled by the frequency envelope
+    pub(crate) phase: f32,
+}
+
+impl ProfileShake {
+    pub(crate) fn new(profile: Handle<ShakeProfile>, scale: f32) -> Self {
+        Self {
+            profile,
+            scale,
+            elapsed: 0.,
+            phase: 0.,
+        }
+    }
+
+    /// Advances by `delta` and returns the translation and rotation offsets,
+    /// or `None` once the profile has played out.
+    pub(crate) fn step(&mut self, profile: &ShakeProfile, delta: f32) -> Option<(Vec3, Vec3)> {
+        self.elapsed += delta;
+        if self.elapsed >= profile.intensity.duration() {
+            return None;
+        }
+
+        let frequency = profile.frequency.map_or(1., |f| f.sample(self.elapsed));
+        self.phase += delta * frequency;
+
+        let magnitude = profile.intensity.sample(self.elapsed) * self.scale;
+        Some(Shake::from(profile).sample(self.phase, magnitude))
+    }
+}
+
+#[derive(Default, TypePath)]
+pub(crate) struct ShakeProfileLoader;
+
+#[derive(Debug, Error)]
+pub enum ShakeProfileLoaderError {
+    #[error("could not read shake profile: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("could not parse shake profile: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+}
+
+impl AssetLoader for ShakeProfileLoader {
+    type Asset = ShakeProfile;
+    type Settings = ();
+    type Error = ShakeProfileLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ron::de::from_bytes(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["shake.ron"]
+    }
+}
@@ -0,0 +1,160 @@
+use std::time::Duration;
+
+use bevy::{camera::Viewport, prelude::*};
+
+/// Lays out several real cameras as split screen viewports of one window.
+///
+/// Each camera is normally driven by its own [`Director`](crate::prelude::Director).
+/// Viewports animate to the new layout when cameras are added to or removed
+/// from `cameras`.  Removed cameras are left untouched.
+#[derive(Component, Clone, Debug)]
+pub struct SplitScreen {
+    /// Window the viewports are laid out in
+    pub window: Entity,
+    /// Real cameras, one per player, in layout order
+    pub cameras: Vec<Entity>,
+    /// How long viewports take to reach a new layout
+    pub transition: Duration,
+    pub function: EaseFunction,
+    pub(crate) from: Vec<(Entity, Rect)>,
+    pub(crate) to: Vec<(Entity, Rect)>,
+    pub(crate) elapsed: Duration,
+}
+
+impl SplitScreen {
+    pub fn new(window: Entity, cameras: Vec<Entity>) -> Self {
+        Self {
+            window,
+            cameras,
+            transition: Duration::from_millis(500),
+            function: EaseFunction::CubicInOut,
+            from: Vec::new(),
+            to: Vec::new(),
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    pub fn with_transition(mut self, transition: Duration, function: EaseFunction) -> Self {
+        self.transition = transition;
+        self.function = function;
+        self
+    }
+
+    fn progress(&self) -> f32 {
+        if self.transition.is_zero() {
+            return 1.0;
+        }
+        let progress = (self.elapsed.as_secs_f32() / self.transition.as_secs_f32()).clamp(0.0, 1.0);
+        self.function.sample(progress).unwrap_or(progress)
+    }
+
+    /// Normalized rect currently shown for `camera`, if it is part of the layout.
+    fn rect_at(&self, camera: Entity, progress: f32) -> Option<Rect> {
+        let (_, to) = self.to.iter().find(|(e, _)| *e == camera)?;
+        let from = self
+            .from
+            .iter()
+            .find(|(e, _)| *e == camera)
+            .map_or(*to, |(_, rect)| *rect);
+        Some(Rect {
+            min: from.min.lerp(to.min, progress),
+            max: from.max.lerp(to.max, progress),
+        })
+    }
+}
+
+/// Normalized viewport rects (origin at the top left) for `count` players.
+///
+/// Two players split left and right, three give the first player the top half,
+/// and four or more are laid out in a grid.
+pub fn split_screen_rects(count: usize) -> Vec<Rect> {
+    match count {
+        0 => Vec::new(),
+        1 => vec![Rect::new(0., 0., 1., 1.)],
+        2 => vec![Rect::new(0., 0., 0.5, 1.), Rect::new(0.5, 0., 1., 1.)],
+        3 => vec![
+            Rect::new(0., 0., 1., 0.5),
+            Rect::new(0., 0.5, 0.5, 1.),
+            Rect::new(0.5, 0.5, 1., 1.),
+        ],
+        _ => {
+            let columns = (count as f32).sqrt().ceil() as usize;
+            let rows = count.div_ceil(columns);
+            let size = Vec2::new(1.0 / columns as f32, 1.0 / rows as f32);
+            (0..count)
+                .map(|i| {
+                    let min = Vec2::new((i % columns) as f32, (i / columns) as f32) * size;
+                    Rect::from_corners(min, min + size)
+                })
+                .collect()
+        }
+    }
+}
+
+pub(crate) fn split_screen_system(
+    time: Res<Time<Real>>,
+    mut layouts: Query<&mut SplitScreen>,
+    windows: Query<&Window>,
+    mut cameras: Query<&mut Camera>,
+) {
+    for mut layout in layouts.iter_mut() {
+        let Ok(window) = windows.get(layout.window) else {
+            continue;
+        };
+        let window_size = window.physical_size().as_vec2();
+
+        // Players joined or left, animate from wherever the viewports are now
+        let targets = layout
+            .cameras
+            .iter()
+            .copied()
+            .zip(split_screen_rects(layout.cameras.len()))
+            .collect::<Vec<_>>();
+        if targets != layout.to {
+            let progress = layout.progress();
+            let from = targets
+                .iter()
+                .map(|(camera, rect)| {
+                    let current = layout
+                        .rect_at(*camera, progress)
+                        .unwrap_or_else(|| Rect::from_center_size(rect.center(), Vec2::ZERO));
+                    (*camera, current)
+                })
+                .collect();
+            layout.from = from;
+            layout.to = targets;
+            layout.elapsed = Duration::ZERO;
+        }
+
+        layout.elapsed += time.delta();
+        let progress = layout.progress();
+
+        for (camera_entity, _) in layout.to.iter() {
+            let Some(rect) = layout.rect_at(*camera_entity, progress) else {
+                continue;
+            };
+            let Ok(mut camera) = cameras.get_mut(*camera_entity) else {
+                continue;
+            };
+
+            let physical_position = (rect.min * window_size).round().as_uvec2();
+            let physical_size = (rect.size() * window_size)
+                .round()
+                .as_uvec2()
+                .max(UVec2::ONE);
+
+            // Only touch the camera when the viewport moves, so aspect ratios aren't resynced every frame
+            let current = camera
+                .viewport
+                .as_ref()
+                .map(|v| (v.physical_position, v.physical_size));
+            if current != Some((physical_position, physical_size)) {
+                camera.viewport = Some(Viewport {
+                    physical_position,
+                    physical_size,
+                    ..default()
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use bevy::{camera::primitives::Aabb, prelude::*};
+
+/// A member of a [`TargetGroup`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TargetGroupMember {
+    pub entity: Entity,
+    /// Pull on the group center relative to the other members.  Members with
+    /// no weight are ignored.
+    pub weight: f32,
+    /// Bounding sphere radius.  0 uses the entity's `Aabb` when it has one.
+    pub radius: f32,
+}
+
+/// Weighted set of entities framed together by [`FollowGroup`](crate::prelude::FollowGroup),
+/// [`LookAtGroup`](crate::prelude::LookAtGroup) and [`GroupZoom`](crate::prelude::GroupZoom).
+///
+/// Bounds are recomputed every frame before the virtual camera systems run.
+/// Members that are missing or have no transform are skipped.
+#[derive(Component, Debug, Clone, Default)]
+pub struct TargetGroup {
+    pub members: Vec<TargetGroupMember>,
+    pub(crate) bounds: Option<GroupBounds>,
+}
+
+/// World space bounds of a [`TargetGroup`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GroupBounds {
+    /// Weighted center of the members
+    pub center: Vec3,
+    /// Radius of a sphere around `center` enclosing every member
+    pub radius: f32,
+    /// Box enclosing every member
+    pub min: Vec3,
+    pub max: Vec3,
+    /// Bounding sphere (center, radius) of each member
+    pub spheres: Vec<(Vec3, f32)>,
+}
+
+impl TargetGroup {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a member, replacing it if it is already in the group.
+    pub fn with(mut self, entity: Entity, weight: f32, radius: f32) -> Self {
+        self.add(entity, weight, radius);
+        self
+    }
+
+    pub fn add(&mut self, entity: Entity, weight: f32, radius: f32) {
+        self.remove(entity);
+        self.members.push(TargetGroupMember {
+            entity,
+            weight,
+            radius,
+        });
+    }
+
+    pub fn remove(&mut self, entity: Entity) {
+        self.members.retain(|m| m.entity != entity);
+    }
+
+    /// Bounds as of the last update, `None` if no member could be found.
+    pub fn bounds(&self) -> Option<&GroupBounds> {
+        self.bounds.as_ref()
+    }
+}
+
+impl FromIterator<Entity> for TargetGroup {
+    /// Members with a weight of 1, sized by their `Aabb`.
+    fn from_iter<T: IntoIterator<Item = Entity>>(iter: T) -> Self {
+        iter.into_iter()
+            .fold(Self::new(), |group, entity| group.with(entity, 1., 0.))
+    }
+}
+
+pub(crate) fn update_target_groups(
+    mut groups: Query<&mut TargetGroup>,
+    helper: TransformHelper,
+    aabbs: Query<&Aabb>,
+) {
+    for mut group in groups.iter_mut() {
+        let mut spheres = Vec::with_capacity(group.members.len());
+        let mut weighted = Vec3::ZERO;
+        let mut total_weight = 0.;
+
+        for member in group.members.iter().filter(|m| m.weight > 0.) {
+            let Ok(global) = helper.compute_global_transform(member.entity) else {
+                continue;
+            };
+
+            // Local space Aabb to a world space sphere
+            let sphere = match aabbs.get(member.entity) {
+                Ok(aabb) if member.radius <= 0. => {
+                    let (scale, _, _) = global.to_scale_rotation_translation();
+                    (
+                        global.transform_point(aabb.center.into()),
+                        (Vec3::from(aabb.half_extents) * scale.abs()).length(),
+                    )
+                }
+                _ => (global.translation(), member.radius.max(0.)),
+            };
+
+            weighted += sphere.0 * member.weight;
+            total_weight += member.weight;
+            spheres.push(sphere);
+        }
+
+        if spheres.is_empty() {
+            group.bounds = None;
+            continue;
+        }
+
+        let center = weighted / total_weight;
+        let (min, max, radius) = spheres.iter().fold(
+            (Vec3::INFINITY, Vec3::NEG_INFINITY, 0f32),
+            |(min, max, radius), (c, r)| {
+                (
+                    min.min(*c - *r),
+                    max.max(*c + *r),
+                    radius.max(c.distance(center) + r),
+                )
+            },
+        );
+
+        group.bounds = Some(GroupBounds {
+            center,
+            radius,
+            min,
+            max,
+            spheres,
+        });
+    }
+}
@@ -5,10 +5,23 @@ use bevy::{prelude::*, window::WindowResized};
 #[require(Transform, Projection)]
 pub struct VirtualCamera {
     pub priority: i32,
+    /// Disabled cameras are never live, whatever their priority.
+    pub enabled: bool,
     pub blend_in: CameraBlendDefinition,
     pub director: Entity,
 }
 
+impl Default for VirtualCamera {
+    fn default() -> Self {
+        Self {
+            priority: 0,
+            enabled: true,
+            blend_in: CameraBlendDefinition::default(),
+            director: Entity::PLACEHOLDER,
+        }
+    }
+}
+
 pub(crate) fn camera_apply_system(
     directors: Query<&Director>,
     vcams: Query<(&Transform, &mut Projection), With<VirtualCamera>>,
@@ -16,7 +29,7 @@ pub(crate) fn camera_apply_system(
 ) {
     for director in directors.iter() {
         if director.blend.is_some() {
-            return;
+            continue;
         }
 
         let active_vcam = match director.active {
@@ -72,7 +85,8 @@ pub(crate) fn on_window_resize(
             let Ok(camera) = cameras.get(director.camera_entity) else {
                 continue;
             };
-            if let Some(size) = camera.logical_target_size() {
+            // Viewport size, so split screen cameras keep their own aspect
+            if let Some(size) = camera.logical_viewport_size() {
                 let aspect = size.x / size.y;
                 set_aspect_ratio(&mut *proj, aspect);
             }
@@ -81,14 +95,10 @@ pub(crate) fn on_window_resize(
 }
 
 pub(crate) fn sync_aspect_ratios(
-    mut vcams: Query<(&VirtualCamera, &mut Projection), Changed<Projection>>,
+    mut vcams: Query<(&VirtualCamera, &mut Projection)>,
     directors: Query<&Director>,
-    cameras: Query<&Camera>,
+    cameras: Query<Ref<Camera>>,
 ) {
-    if vcams.count() == 0 {
-        return;
-    }
-
     for (vcam, mut proj) in vcams.iter_mut() {
         let Ok(director) = directors.get(vcam.director) else {
             continue;
@@ -96,7 +106,13 @@ pub(crate) fn sync_aspect_ratios(
         let Ok(camera) = cameras.get(director.camera_entity) else {
             continue;
         };
-        if let Some(size) = camera.logical_target_size() {
+
+        // Resync when either side changes, e.g. an animated split screen viewport
+        if !proj.is_changed() && !camera.is_changed() {
+            continue;
+        }
+
+        if let Some(size) = camera.logical_viewport_size() {
             let aspect = size.x / size.y;
             set_aspect_ratio(&mut *proj, aspect);
         }